@@ -0,0 +1,249 @@
+//! Derive macros for [`bumpalo`](https://docs.rs/bumpalo).
+//!
+//! Don't depend on this crate directly. Instead, enable `bumpalo`'s
+//! `"derive"` feature, which re-exports these derives next to the traits
+//! they implement.
+
+#![deny(missing_docs)]
+
+extern crate proc_macro;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Fields, GenericParam, Ident, Lifetime,
+    PathArguments, Type, WherePredicate,
+};
+
+/// Derive `bumpalo::CloneIn` for a struct or enum.
+///
+/// The type's first lifetime parameter is taken to be the lifetime of the
+/// arena that it lives in. The derived `clone_in` rebuilds the value with
+/// that lifetime replaced by the new arena's lifetime, by calling `clone_in`
+/// on each of its fields. Each type parameter `T` is required to implement
+/// `CloneIn` too, and is replaced by its `T::Cloned` type in the copy. Any
+/// other lifetime parameters are kept as they are, and so are required to
+/// outlive the new arena.
+///
+/// ```
+/// use bumpalo::{collections::Vec, Bump, CloneIn};
+///
+/// #[derive(CloneIn)]
+/// enum Expr<'bump> {
+///     Number(u64),
+///     Name(&'bump str),
+///     Call(&'bump Expr<'bump>, Vec<'bump, Expr<'bump>>),
+/// }
+///
+/// fn rehome<'new>(expr: &Expr<'_>, bump: &'new Bump) -> Expr<'new> {
+///     expr.clone_in(bump)
+/// }
+///
+/// let long_lived = Bump::new();
+/// let scratch = Bump::new();
+/// let f = scratch.alloc(Expr::Name("f"));
+/// let call = Expr::Call(f, bumpalo::vec![in &scratch; Expr::Number(1)]);
+///
+/// match rehome(&call, &long_lived) {
+///     Expr::Call(Expr::Name("f"), args) => assert!(matches!(args[..], [Expr::Number(1)])),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[proc_macro_derive(CloneIn)]
+pub fn derive_clone_in(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match clone_in(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn clone_in(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let new_lt = Lifetime::new("'__new", Span::call_site());
+    let arena_lt = input
+        .generics
+        .lifetimes()
+        .next()
+        .map(|def| def.lifetime.clone());
+    let params: Vec<Ident> = input
+        .generics
+        .type_params()
+        .map(|p| p.ident.clone())
+        .collect();
+    let mut rehome = Rehome {
+        arena: arena_lt.as_ref(),
+        new: &new_lt,
+        params: &params,
+    };
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pat, expr) = clone_fields(quote!(#name), &data.fields);
+            quote! {
+                let #pat = self;
+                #expr
+            }
+        }
+        Data::Enum(data) if data.variants.is_empty() => quote!(match *self {}),
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let (pat, expr) = clone_fields(quote!(#name::#ident), &variant.fields);
+                quote!(#pat => #expr,)
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span,
+                "`CloneIn` can't be derived for unions",
+            ));
+        }
+    };
+
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    // The copy's type is this type, with the arena lifetime and the type
+    // parameters swapped out.
+    let mut cloned_ty: Type = parse_quote!(#name #ty_generics);
+    rehome.visit_type_mut(&mut cloned_ty);
+
+    // The impl keeps all of the type's own bounds, requires each type
+    // parameter to implement `CloneIn` and each other lifetime to outlive the
+    // new arena, and requires the copy's type to meet the same bounds as the
+    // original.
+    let mut generics = input.generics.clone();
+    let mut cloned_bounds: Vec<WherePredicate> = vec![];
+    for param in &input.generics.params {
+        match param {
+            GenericParam::Lifetime(def) if !def.bounds.is_empty() => {
+                let (lt, bounds) = (&def.lifetime, &def.bounds);
+                cloned_bounds.push(parse_quote!(#lt: #bounds));
+            }
+            GenericParam::Type(param) if !param.bounds.is_empty() => {
+                let (ident, bounds) = (&param.ident, &param.bounds);
+                cloned_bounds.push(parse_quote!(#ident: #bounds));
+            }
+            _ => {}
+        }
+    }
+    if let Some(where_clause) = &input.generics.where_clause {
+        cloned_bounds.extend(where_clause.predicates.iter().cloned());
+    }
+    let where_clause = generics.make_where_clause();
+    for ident in &params {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ident: ::bumpalo::CloneIn<#new_lt>));
+    }
+    for def in input.generics.lifetimes().skip(1) {
+        let lt = &def.lifetime;
+        where_clause.predicates.push(parse_quote!(#lt: #new_lt));
+    }
+    for mut predicate in cloned_bounds {
+        rehome.visit_where_predicate_mut(&mut predicate);
+        where_clause.predicates.push(predicate);
+    }
+    generics.params.insert(0, parse_quote!(#new_lt));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::bumpalo::CloneIn<#new_lt> for #name #ty_generics #where_clause {
+            type Cloned = #cloned_ty;
+
+            #[allow(unused_variables)]
+            fn clone_in(&self, bump: &#new_lt ::bumpalo::Bump) -> Self::Cloned {
+                #body
+            }
+        }
+    })
+}
+
+// Build a pattern that binds each of the fields of the struct or variant at
+// `path` by reference, and an expression that rebuilds it from their copies.
+fn clone_fields(path: TokenStream, fields: &Fields) -> (TokenStream, TokenStream) {
+    let bindings: Vec<Ident> = (0..fields.iter().count())
+        .map(|i| Ident::new(&format!("__self_{}", i), Span::call_site()))
+        .collect();
+    let bindings = &bindings;
+    let clones: Vec<TokenStream> = bindings
+        .iter()
+        .map(|b| quote!(::bumpalo::CloneIn::clone_in(#b, bump)))
+        .collect();
+    let clones = &clones;
+
+    match fields {
+        Fields::Named(fields) => {
+            let names: Vec<&Ident> = fields
+                .named
+                .iter()
+                .map(|f| f.ident.as_ref().unwrap())
+                .collect();
+            let names = &names;
+            (
+                quote!(#path { #(#names: #bindings),* }),
+                quote!(#path { #(#names: #clones),* }),
+            )
+        }
+        Fields::Unnamed(_) => (
+            quote!(#path( #(#bindings),* )),
+            quote!(#path( #(#clones),* )),
+        ),
+        Fields::Unit => (path.clone(), path),
+    }
+}
+
+// Rewrites a type from the original arena to the new one: the arena lifetime
+// becomes the new arena's lifetime, and each type parameter `T` becomes
+// `<T as CloneIn<'new>>::Cloned`.
+struct Rehome<'a> {
+    arena: Option<&'a Lifetime>,
+    new: &'a Lifetime,
+    params: &'a [Ident],
+}
+
+impl<'a> Rehome<'a> {
+    // If `ty` is just one of the type parameters, return it.
+    fn param(&self, ty: &Type) -> Option<Ident> {
+        let path = match ty {
+            Type::Path(ty) if ty.qself.is_none() => &ty.path,
+            _ => return None,
+        };
+        if path.leading_colon.is_some() || path.segments.len() != 1 {
+            return None;
+        }
+        let segment = &path.segments[0];
+        match segment.arguments {
+            PathArguments::None if self.params.contains(&segment.ident) => {
+                Some(segment.ident.clone())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a> VisitMut for Rehome<'a> {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if let Some(arena) = self.arena {
+            if arena.ident == lifetime.ident {
+                *lifetime = self.new.clone();
+            }
+        }
+    }
+
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Some(ident) = self.param(ty) {
+            let new = self.new;
+            *ty = parse_quote!(<#ident as ::bumpalo::CloneIn<#new>>::Cloned);
+            return;
+        }
+        visit_mut::visit_type_mut(self, ty);
+    }
+}
@@ -0,0 +1,40 @@
+/// Tuples of values that can be allocated together with
+/// [`Bump::alloc_many`](./struct.Bump.html#method.alloc_many).
+///
+/// This is implemented for tuples of up to twelve values.
+pub trait AllocMany<'a> {
+    /// A tuple of exclusive references to each of the values.
+    type Refs;
+
+    /// Split an exclusive reference to this tuple into exclusive references
+    /// to each of its values.
+    fn split_mut(tuple: &'a mut Self) -> Self::Refs;
+}
+
+macro_rules! impl_alloc_many {
+    ( $( $ty:ident )+ ) => {
+        impl<'a, $( $ty: 'a ),+> AllocMany<'a> for ( $( $ty, )+ ) {
+            type Refs = ( $( &'a mut $ty, )+ );
+
+            #[inline(always)]
+            #[allow(non_snake_case)]
+            fn split_mut(tuple: &'a mut Self) -> Self::Refs {
+                let ( $( $ty, )+ ) = tuple;
+                ( $( $ty, )+ )
+            }
+        }
+    };
+}
+
+impl_alloc_many!(A);
+impl_alloc_many!(A B);
+impl_alloc_many!(A B C);
+impl_alloc_many!(A B C D);
+impl_alloc_many!(A B C D E);
+impl_alloc_many!(A B C D E F);
+impl_alloc_many!(A B C D E F G);
+impl_alloc_many!(A B C D E F G H);
+impl_alloc_many!(A B C D E F G H I);
+impl_alloc_many!(A B C D E F G H I J);
+impl_alloc_many!(A B C D E F G H I J K);
+impl_alloc_many!(A B C D E F G H I J K L);
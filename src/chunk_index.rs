@@ -0,0 +1,95 @@
+use crate::imports::*;
+use crate::{chunk_allocated_range, Bump, ChunkFooter};
+
+/// A sorted index of a `Bump`'s chunks, for finding out whether a pointer
+/// points into the arena in time logarithmic in the number of chunks.
+///
+/// The index is a snapshot of the arena's chunks when it was built. It keeps
+/// the arena borrowed, so none of its chunks can be freed, but new chunks can
+/// still be added by allocating. Pointers into chunks that are missing from
+/// the index are found by falling back to
+/// [`Bump::contains`](./struct.Bump.html#method.contains), so the index never
+/// gives the wrong answer; call [`refresh`](#method.refresh) to bring it up
+/// to date and make it fast again.
+///
+/// Get one with [`Bump::chunk_index`](./struct.Bump.html#method.chunk_index).
+///
+/// ## Example
+///
+/// ```
+/// let bump = bumpalo::Bump::new();
+/// let x = bump.alloc(1_u64);
+///
+/// let mut index = bump.chunk_index();
+/// assert!(index.contains(x as *const u64 as *const u8));
+///
+/// // Allocating after the index is built still gives correct answers...
+/// let y = bump.alloc([2_u64; 1000]);
+/// assert!(index.contains(y as *const [u64; 1000] as *const u8));
+///
+/// // ...but refreshing the index makes them fast again.
+/// assert!(index.is_stale());
+/// index.refresh();
+/// assert!(!index.is_stale());
+/// ```
+#[derive(Debug)]
+pub struct ChunkIndex<'a, const MIN_ALIGN: usize = 1> {
+    bump: &'a Bump<MIN_ALIGN>,
+
+    // The start address of each chunk along with its footer, sorted by start
+    // address.
+    chunks: Vec<(usize, NonNull<ChunkFooter>)>,
+
+    // The ends of the arena's chunk list when this index was built.
+    ends: (Option<NonNull<ChunkFooter>>, Option<NonNull<ChunkFooter>>),
+}
+
+impl<'a, const MIN_ALIGN: usize> ChunkIndex<'a, MIN_ALIGN> {
+    pub(crate) fn new(bump: &'a Bump<MIN_ALIGN>) -> Self {
+        let mut index = ChunkIndex {
+            bump,
+            chunks: Vec::new(),
+            ends: (None, None),
+        };
+        index.refresh();
+        index
+    }
+
+    /// Rebuild this index to include all of the arena's current chunks.
+    pub fn refresh(&mut self) {
+        self.chunks.clear();
+        self.chunks.extend(
+            self.bump
+                .chunk_footers()
+                .map(|f| unsafe { (f.as_ref().data.as_ptr() as usize, f) }),
+        );
+        self.chunks.sort_unstable_by_key(|&(start, _)| start);
+        self.ends = self.bump.chunk_list_ends();
+    }
+
+    /// Returns `true` if chunks have been added to the arena since this index
+    /// was built or last [`refresh`](#method.refresh)ed.
+    pub fn is_stale(&self) -> bool {
+        self.ends != self.bump.chunk_list_ends()
+    }
+
+    /// Returns `true` if `ptr` points into memory that has been allocated in
+    /// the arena.
+    ///
+    /// See [`Bump::contains`](./struct.Bump.html#method.contains) for
+    /// details.
+    pub fn contains(&self, ptr: *const u8) -> bool {
+        let addr = ptr as usize;
+
+        // Find the last chunk that starts at or before `addr`.
+        let i = self.chunks.partition_point(|&(start, _)| start <= addr);
+        if i > 0 {
+            let footer = unsafe { self.chunks[i - 1].1.as_ref() };
+            if chunk_allocated_range(footer).contains(&addr) {
+                return true;
+            }
+        }
+
+        self.is_stale() && self.bump.contains(ptr)
+    }
+}
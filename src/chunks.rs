@@ -0,0 +1,104 @@
+use crate::imports::*;
+use crate::{chunk_allocated_range, ChunkFooter};
+
+/// One of a `Bump`'s chunks, as yielded by
+/// [`Bump::iter_allocated_chunks`](./struct.Bump.html#method.iter_allocated_chunks).
+#[derive(Clone, Copy, Debug)]
+pub struct AllocatedChunk<'a> {
+    /// The part of the chunk that has been allocated into.
+    ///
+    /// This may contain uninitialized padding between allocations, which is
+    /// why its bytes are `MaybeUninit<u8>`.
+    pub bytes: &'a [MaybeUninit<u8>],
+
+    /// The total number of bytes that can be allocated in the chunk.
+    pub capacity: usize,
+}
+
+/// An iterator over a `Bump`'s chunks.
+///
+/// Created by
+/// [`Bump::iter_allocated_chunks`](./struct.Bump.html#method.iter_allocated_chunks).
+#[derive(Debug)]
+pub struct AllocatedChunks<'a> {
+    footer: Option<NonNull<ChunkFooter>>,
+    _bump: PhantomData<&'a mut ()>,
+}
+
+impl<'a> AllocatedChunks<'a> {
+    pub(crate) unsafe fn new(footer: Option<NonNull<ChunkFooter>>) -> Self {
+        AllocatedChunks {
+            footer,
+            _bump: PhantomData,
+        }
+    }
+}
+
+impl<'a> Iterator for AllocatedChunks<'a> {
+    type Item = AllocatedChunk<'a>;
+
+    fn next(&mut self) -> Option<AllocatedChunk<'a>> {
+        let raw = next_raw_chunk(&mut self.footer)?;
+        unsafe {
+            Some(AllocatedChunk {
+                bytes: slice::from_raw_parts(raw.start as *const MaybeUninit<u8>, raw.used),
+                capacity: raw.capacity,
+            })
+        }
+    }
+}
+
+/// Raw information about one of a `Bump`'s chunks, as yielded by
+/// [`Bump::iter_allocated_chunks_raw`](./struct.Bump.html#method.iter_allocated_chunks_raw).
+#[derive(Clone, Copy, Debug)]
+pub struct RawAllocatedChunk {
+    /// The address of the start of the chunk.
+    pub start: *const u8,
+
+    /// The number of bytes at the start of the chunk that have been allocated
+    /// into.
+    pub used: usize,
+
+    /// The total number of bytes that can be allocated in the chunk.
+    pub capacity: usize,
+}
+
+/// An iterator over raw information about a `Bump`'s chunks.
+///
+/// Created by
+/// [`Bump::iter_allocated_chunks_raw`](./struct.Bump.html#method.iter_allocated_chunks_raw).
+#[derive(Debug)]
+pub struct RawAllocatedChunks<'a> {
+    footer: Option<NonNull<ChunkFooter>>,
+    _bump: PhantomData<&'a ()>,
+}
+
+impl<'a> RawAllocatedChunks<'a> {
+    pub(crate) unsafe fn new(footer: Option<NonNull<ChunkFooter>>) -> Self {
+        RawAllocatedChunks {
+            footer,
+            _bump: PhantomData,
+        }
+    }
+}
+
+impl<'a> Iterator for RawAllocatedChunks<'a> {
+    type Item = RawAllocatedChunk;
+
+    fn next(&mut self) -> Option<RawAllocatedChunk> {
+        next_raw_chunk(&mut self.footer)
+    }
+}
+
+// Describe the chunk at `footer`, and advance `footer` to the next chunk.
+fn next_raw_chunk(footer: &mut Option<NonNull<ChunkFooter>>) -> Option<RawAllocatedChunk> {
+    let f = unsafe { (*footer)?.as_ref() };
+    *footer = f.next.get();
+
+    let allocated = chunk_allocated_range(f);
+    Some(RawAllocatedChunk {
+        start: f.data.as_ptr(),
+        used: allocated.end - allocated.start,
+        capacity: f as *const ChunkFooter as usize - allocated.start,
+    })
+}
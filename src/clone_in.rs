@@ -0,0 +1,146 @@
+use crate::imports::*;
+use crate::Bump;
+use core::convert::Infallible;
+
+/// Deep-copying a value into a different arena.
+///
+/// `clone_in` rebuilds a value, and everything it references, inside the
+/// arena it is given. This is useful for moving a finished data structure out
+/// of a short-lived arena into a long-lived one, or for duplicating part of a
+/// structure before mutating it.
+///
+/// The copy's type is [`Cloned`](#associatedtype.Cloned), which is usually
+/// `Self` with its arena lifetime replaced by `'new`.
+///
+/// This is implemented for primitive types, references, `Option`, tuples of
+/// up to twelve values, and the types in [`collections`](./collections/index.html).
+///
+/// ## Example
+///
+/// ```
+/// use bumpalo::{Bump, CloneIn};
+///
+/// let long_lived = Bump::new();
+///
+/// let copy = {
+///     let scratch = Bump::new();
+///     let name: &str = scratch.alloc_fmt(format_args!("item {}", 1));
+///     (name, Some(42)).clone_in(&long_lived)
+/// };
+///
+/// assert_eq!(copy, ("item 1", Some(42)));
+/// assert!(long_lived.contains(copy.0.as_ptr()));
+/// ```
+pub trait CloneIn<'new>: Sized {
+    /// The type of the copy that is allocated in the `'new` arena.
+    type Cloned: 'new;
+
+    /// Deep-copies this value, allocating everything it references in `bump`.
+    fn clone_in(&self, bump: &'new Bump) -> Self::Cloned;
+}
+
+macro_rules! impl_clone_in_for_copy {
+    ( $( $ty:ty ),* ) => {
+        $(
+            impl<'new> CloneIn<'new> for $ty {
+                type Cloned = $ty;
+
+                #[inline(always)]
+                fn clone_in(&self, _bump: &'new Bump) -> $ty {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+impl_clone_in_for_copy!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64
+);
+
+impl<'new, T: CloneIn<'new>> CloneIn<'new> for &T {
+    type Cloned = &'new T::Cloned;
+
+    fn clone_in(&self, bump: &'new Bump) -> Self::Cloned {
+        bump.alloc((**self).clone_in(bump))
+    }
+}
+
+impl<'new, T: CloneIn<'new>> CloneIn<'new> for &[T] {
+    type Cloned = &'new [T::Cloned];
+
+    fn clone_in(&self, bump: &'new Bump) -> Self::Cloned {
+        let cloned = bump
+            .alloc_slice_try_fill_iter(self.iter().map(|x| Ok::<_, Infallible>(x.clone_in(bump))));
+        match cloned {
+            Ok(slice) => slice,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl<'new> CloneIn<'new> for &str {
+    type Cloned = &'new str;
+
+    fn clone_in(&self, bump: &'new Bump) -> &'new str {
+        let bytes = bump.alloc_uninit_slice::<u8>(self.len());
+        unsafe {
+            ptr::copy_nonoverlapping(self.as_ptr(), bytes.as_mut_ptr() as *mut u8, self.len());
+            let bytes = slice::from_raw_parts(bytes.as_ptr() as *const u8, self.len());
+            str::from_utf8_unchecked(bytes)
+        }
+    }
+}
+
+impl<'new, T: CloneIn<'new>> CloneIn<'new> for Option<T> {
+    type Cloned = Option<T::Cloned>;
+
+    #[inline]
+    fn clone_in(&self, bump: &'new Bump) -> Self::Cloned {
+        self.as_ref().map(|x| x.clone_in(bump))
+    }
+}
+
+macro_rules! impl_clone_in_for_tuple {
+    ( $( $ty:ident )+ ) => {
+        impl<'new, $( $ty: CloneIn<'new> ),+> CloneIn<'new> for ( $( $ty, )+ ) {
+            type Cloned = ( $( $ty::Cloned, )+ );
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn clone_in(&self, bump: &'new Bump) -> Self::Cloned {
+                let ( $( $ty, )+ ) = self;
+                ( $( $ty.clone_in(bump), )+ )
+            }
+        }
+    };
+}
+
+impl_clone_in_for_tuple!(A);
+impl_clone_in_for_tuple!(A B);
+impl_clone_in_for_tuple!(A B C);
+impl_clone_in_for_tuple!(A B C D);
+impl_clone_in_for_tuple!(A B C D E);
+impl_clone_in_for_tuple!(A B C D E F);
+impl_clone_in_for_tuple!(A B C D E F G);
+impl_clone_in_for_tuple!(A B C D E F G H);
+impl_clone_in_for_tuple!(A B C D E F G H I);
+impl_clone_in_for_tuple!(A B C D E F G H I J);
+impl_clone_in_for_tuple!(A B C D E F G H I J K);
+impl_clone_in_for_tuple!(A B C D E F G H I J K L);
@@ -0,0 +1,60 @@
+//! Building arena collections from iterators.
+
+use crate::Bump;
+
+/// Conversion from an iterator into a collection that is allocated in an
+/// arena.
+///
+/// This is the arena counterpart of `std`'s `FromIterator`. It is rarely
+/// called directly; use [`CollectIn::collect_in`] instead. Implementations
+/// reserve space for the iterator's `size_hint` lower bound up front.
+///
+/// [`CollectIn::collect_in`]: trait.CollectIn.html#method.collect_in
+///
+/// ## Example
+///
+/// ```
+/// use bumpalo::{Bump, collections::{FromIteratorIn, Vec}};
+///
+/// let b = Bump::new();
+///
+/// let v: Vec<i32> = FromIteratorIn::from_iter_in(1..4, &b);
+/// assert_eq!(v, [1, 2, 3]);
+/// ```
+pub trait FromIteratorIn<'bump, A>: Sized {
+    /// Creates a value from an iterator, allocating it in `bump`.
+    fn from_iter_in<I: IntoIterator<Item = A>>(iter: I, bump: &'bump Bump) -> Self;
+}
+
+/// An extension trait for collecting any iterator into an arena collection.
+///
+/// This is implemented for every iterator.
+///
+/// ## Example
+///
+/// ```
+/// use bumpalo::{Bump, collections::{CollectIn, String, Vec}};
+///
+/// let b = Bump::new();
+///
+/// let squares = (1..4).map(|x| x * x).collect_in::<Vec<_>>(&b);
+/// assert_eq!(squares, [1, 4, 9]);
+///
+/// let s: String = "hello".chars().rev().collect_in(&b);
+/// assert_eq!(s, "olleh");
+/// ```
+pub trait CollectIn: Iterator + Sized {
+    /// Transforms this iterator into a collection allocated in `bump`.
+    ///
+    /// Like `Iterator::collect`, this needs to know which collection to
+    /// build, either from the context or with a turbofish.
+    #[inline]
+    fn collect_in<'bump, C>(self, bump: &'bump Bump) -> C
+    where
+        C: FromIteratorIn<'bump, Self::Item>,
+    {
+        C::from_iter_in(self, bump)
+    }
+}
+
+impl<I: Iterator> CollectIn for I {}
@@ -17,7 +17,18 @@ pub use self::vec::Vec;
 
 mod str;
 pub mod string;
-pub use self::string::String;
+pub use self::string::{String, ToStringIn};
+
+mod str_ext;
+pub use self::str_ext::{JoinIn, SplitIn, TrimIn};
+
+mod collect_in;
+pub use self::collect_in::{CollectIn, FromIteratorIn};
+
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "rayon")]
+pub use self::rayon::CollectIntoBump;
 
 // pub mod binary_heap;
 // mod btree;
@@ -80,6 +80,13 @@ impl<'a, T> RawVec<'a, T> {
         RawVec::allocate_in(cap, false, a)
     }
 
+    /// The same as `with_capacity_in`, but returns on errors instead of
+    /// panicking or aborting.
+    #[inline]
+    pub fn try_with_capacity_in(cap: usize, a: &'a Bump) -> Result<Self, CollectionAllocErr> {
+        RawVec::allocate_in_internal(cap, false, a, Fallible)
+    }
+
     /// Like `with_capacity_zeroed` but parameterized over the choice
     /// of allocator for the returned RawVec.
     #[inline]
@@ -87,37 +94,50 @@ impl<'a, T> RawVec<'a, T> {
         RawVec::allocate_in(cap, true, a)
     }
 
-    fn allocate_in(cap: usize, zeroed: bool, mut a: &'a Bump) -> Self {
+    fn allocate_in(cap: usize, zeroed: bool, a: &'a Bump) -> Self {
+        match RawVec::allocate_in_internal(cap, zeroed, a, Infallible) {
+            Err(CapacityOverflow) => capacity_overflow(),
+            Err(AllocErr) => unreachable!(),
+            Ok(buf) => buf,
+        }
+    }
+
+    fn allocate_in_internal(
+        cap: usize,
+        zeroed: bool,
+        mut a: &'a Bump,
+        fallibility: Fallibility,
+    ) -> Result<Self, CollectionAllocErr> {
         unsafe {
             let elem_size = mem::size_of::<T>();
 
-            let alloc_size = cap
-                .checked_mul(elem_size)
-                .unwrap_or_else(|| capacity_overflow());
-            alloc_guard(alloc_size).unwrap_or_else(|_| capacity_overflow());
+            let alloc_size = cap.checked_mul(elem_size).ok_or(CapacityOverflow)?;
+            alloc_guard(alloc_size)?;
 
             // handles ZSTs and `cap = 0` alike
             let ptr = if alloc_size == 0 {
                 NonNull::<T>::dangling()
             } else {
                 let align = mem::align_of::<T>();
-                let layout = Layout::from_size_align(alloc_size, align).unwrap();
+                let layout =
+                    Layout::from_size_align(alloc_size, align).map_err(|_| CapacityOverflow)?;
                 let result = if zeroed {
-                    a.alloc_zeroed(layout)
+                    Alloc::alloc_zeroed(&mut a, layout)
                 } else {
                     Alloc::alloc(&mut a, layout)
                 };
-                match result {
-                    Ok(ptr) => ptr.cast(),
-                    Err(_) => handle_alloc_error(layout),
+                match (result, fallibility) {
+                    (Ok(ptr), _) => ptr.cast(),
+                    (Err(_), Infallible) => handle_alloc_error(layout),
+                    (Err(_), Fallible) => return Err(AllocErr),
                 }
             };
 
-            RawVec {
+            Ok(RawVec {
                 ptr: ptr.into(),
                 cap,
                 a,
-            }
+            })
         }
     }
 }
@@ -0,0 +1,332 @@
+//! Integration with `rayon`'s parallel iterators.
+//!
+//! A `Bump` can't be shared between threads, so the arena-backed collection
+//! is only touched from the calling thread. When a parallel iterator knows its
+//! exact length up front, the collection reserves exactly that much capacity
+//! and the worker threads write their items straight into disjoint parts of
+//! it. Otherwise, the items are gathered into per-thread buffers first, and
+//! these are concatenated into the collection afterwards, after reserving the
+//! exact total capacity.
+
+use crate::collections::{String, Vec};
+use crate::Bump;
+use rayon::iter::plumbing::{Consumer, Folder, Reducer, UnindexedConsumer};
+use rayon::iter::{IntoParallelIterator, ParallelExtend, ParallelIterator};
+use std::collections::LinkedList;
+use std::mem::MaybeUninit;
+use std::slice;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// An extension trait for collecting any parallel iterator into a
+/// `collections::Vec` that is allocated in an arena.
+///
+/// This is implemented for every parallel iterator.
+///
+/// # Examples
+///
+/// ```
+/// use bumpalo::{Bump, collections::CollectIntoBump};
+/// use rayon::prelude::*;
+///
+/// let b = Bump::new();
+/// let squares = (0..1000_u64).into_par_iter().map(|x| x * x).collect_into_bump(&b);
+/// assert_eq!(squares.len(), 1000);
+/// assert_eq!(squares.capacity(), 1000);
+/// assert_eq!(squares[999], 998_001);
+/// ```
+pub trait CollectIntoBump: ParallelIterator {
+    /// Collects this parallel iterator's items, in order, into a `Vec`
+    /// allocated in `bump`.
+    ///
+    /// The vector is allocated with exactly the capacity needed to hold all
+    /// of the items.
+    fn collect_into_bump<'bump>(self, bump: &'bump Bump) -> Vec<'bump, Self::Item> {
+        Vec::from_par_iter_in(self, bump)
+    }
+}
+
+impl<I: ParallelIterator> CollectIntoBump for I {}
+
+/// Write the items of `par_iter`, which yields exactly `len` items, into the
+/// spare capacity of `v`, which must have room for them.
+///
+/// Like `rayon`'s own `Vec` collection, this relies on `opt_len` only being
+/// `Some` for indexed parallel iterators, which split their consumers at
+/// exact positions and never ask for unindexed splits.
+fn collect_exact<I, T>(par_iter: I, len: usize, v: &mut Vec<T>)
+where
+    I: ParallelIterator<Item = T>,
+    T: Send,
+{
+    let writes = AtomicUsize::new(0);
+    par_iter.drive_unindexed(CollectConsumer {
+        writes: &writes,
+        target: &mut v.spare_capacity_mut()[..len],
+    });
+
+    // The consumer can't have escaped `drive_unindexed`, so all of its
+    // writes have happened by now.
+    let actual = writes.load(Ordering::Relaxed);
+    assert_eq!(actual, len, "expected {} total writes, but got {}", len, actual);
+    unsafe {
+        let new_len = v.len() + len;
+        v.set_len(new_len);
+    }
+}
+
+// Writes items into its slice of uninitialized memory, and counts them.
+struct CollectConsumer<'c, T: Send> {
+    writes: &'c AtomicUsize,
+    target: &'c mut [MaybeUninit<T>],
+}
+
+struct CollectFolder<'c, T: Send> {
+    writes: &'c AtomicUsize,
+    local_writes: usize,
+    target: slice::IterMut<'c, MaybeUninit<T>>,
+}
+
+struct CollectReducer;
+
+impl Reducer<()> for CollectReducer {
+    fn reduce(self, _left: (), _right: ()) {}
+}
+
+impl<'c, T: Send> Consumer<T> for CollectConsumer<'c, T> {
+    type Folder = CollectFolder<'c, T>;
+    type Reducer = CollectReducer;
+    type Result = ();
+
+    fn split_at(self, index: usize) -> (Self, Self, CollectReducer) {
+        let CollectConsumer { writes, target } = self;
+        let (left, right) = target.split_at_mut(index);
+        (
+            CollectConsumer {
+                writes,
+                target: left,
+            },
+            CollectConsumer {
+                writes,
+                target: right,
+            },
+            CollectReducer,
+        )
+    }
+
+    fn into_folder(self) -> CollectFolder<'c, T> {
+        CollectFolder {
+            writes: self.writes,
+            local_writes: 0,
+            target: self.target.iter_mut(),
+        }
+    }
+
+    fn full(&self) -> bool {
+        false
+    }
+}
+
+impl<'c, T: Send> Folder<T> for CollectFolder<'c, T> {
+    type Result = ();
+
+    fn consume(mut self, item: T) -> Self {
+        let slot = self
+            .target
+            .next()
+            .expect("too many values pushed to consumer");
+        slot.write(item);
+        self.local_writes += 1;
+        self
+    }
+
+    fn complete(self) {
+        assert!(self.target.len() == 0, "too few values pushed to consumer");
+        self.writes.fetch_add(self.local_writes, Ordering::Relaxed);
+    }
+
+    fn full(&self) -> bool {
+        false
+    }
+}
+
+impl<'c, T: Send> UnindexedConsumer<T> for CollectConsumer<'c, T> {
+    fn split_off_left(&self) -> Self {
+        unreachable!("only indexed parallel iterators are collected in place")
+    }
+
+    fn to_reducer(&self) -> CollectReducer {
+        CollectReducer
+    }
+}
+
+/// Collect `par_iter` into a list of per-thread buffers.
+fn collect_buffers<I, T>(par_iter: I) -> LinkedList<::std::vec::Vec<T>>
+where
+    I: IntoParallelIterator<Item = T>,
+    T: Send,
+{
+    par_iter
+        .into_par_iter()
+        .fold(::std::vec::Vec::new, |mut buf, elem| {
+            buf.push(elem);
+            buf
+        })
+        .map(|buf| {
+            let mut list = LinkedList::new();
+            list.push_back(buf);
+            list
+        })
+        .reduce(LinkedList::new, |mut a, mut b| {
+            a.append(&mut b);
+            a
+        })
+}
+
+/// Collect `par_iter` into a list of per-thread string buffers.
+fn collect_string_buffers<I, F>(par_iter: I, push: F) -> LinkedList<::std::string::String>
+where
+    I: IntoParallelIterator,
+    F: Fn(&mut ::std::string::String, I::Item) + Sync + Send,
+{
+    par_iter
+        .into_par_iter()
+        .fold(::std::string::String::new, |mut buf, item| {
+            push(&mut buf, item);
+            buf
+        })
+        .map(|buf| {
+            let mut list = LinkedList::new();
+            list.push_back(buf);
+            list
+        })
+        .reduce(LinkedList::new, |mut a, mut b| {
+            a.append(&mut b);
+            a
+        })
+}
+
+impl<'bump, T: Send> ParallelExtend<T> for Vec<'bump, T> {
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        let par_iter = par_iter.into_par_iter();
+        match par_iter.opt_len() {
+            Some(len) => {
+                self.reserve_exact(len);
+                collect_exact(par_iter, len, self);
+            }
+            None => {
+                let buffers = collect_buffers(par_iter);
+                self.reserve_exact(buffers.iter().map(|buf| buf.len()).sum());
+                for buf in buffers {
+                    self.extend(buf);
+                }
+            }
+        }
+    }
+}
+
+impl<'bump, T: Send> Vec<'bump, T> {
+    /// Construct a new `Vec` from the given parallel iterator's items.
+    ///
+    /// The vector is allocated with exactly the capacity needed to hold all
+    /// of the items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    /// use rayon::prelude::*;
+    ///
+    /// let b = Bump::new();
+    /// let v = Vec::from_par_iter_in((0..100).into_par_iter().map(|x| x * 2), &b);
+    /// assert_eq!(v.len(), 100);
+    /// assert_eq!(v.capacity(), 100);
+    /// assert_eq!(v[99], 198);
+    /// ```
+    pub fn from_par_iter_in<I>(par_iter: I, bump: &'bump Bump) -> Vec<'bump, T>
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        let mut v = Vec::new_in(bump);
+        v.par_extend(par_iter);
+        v
+    }
+}
+
+impl<'bump> String<'bump> {
+    fn extend_from_buffers(&mut self, buffers: LinkedList<::std::string::String>) {
+        self.reserve_exact(buffers.iter().map(|buf| buf.len()).sum());
+        for buf in buffers {
+            self.push_str(&buf);
+        }
+    }
+
+    /// Construct a new `String` from the given parallel iterator's items.
+    ///
+    /// The string is allocated with exactly the capacity needed to hold all
+    /// of the items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::String};
+    /// use rayon::prelude::*;
+    ///
+    /// let b = Bump::new();
+    /// let words = ["hello", " ", "world"];
+    /// let s = String::from_par_iter_in(words.par_iter().cloned(), &b);
+    /// assert_eq!(s, "hello world");
+    /// ```
+    pub fn from_par_iter_in<I>(par_iter: I, bump: &'bump Bump) -> String<'bump>
+    where
+        I: IntoParallelIterator,
+        I::Item: Send,
+        String<'bump>: ParallelExtend<I::Item>,
+    {
+        let mut s = String::new_in(bump);
+        s.par_extend(par_iter);
+        s
+    }
+}
+
+impl<'bump> ParallelExtend<char> for String<'bump> {
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = char>,
+    {
+        let buffers = collect_string_buffers(par_iter, |buf, c| buf.push(c));
+        self.extend_from_buffers(buffers);
+    }
+}
+
+impl<'a, 'bump> ParallelExtend<&'a char> for String<'bump> {
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = &'a char>,
+    {
+        let buffers = collect_string_buffers(par_iter, |buf, c| buf.push(*c));
+        self.extend_from_buffers(buffers);
+    }
+}
+
+impl<'a, 'bump> ParallelExtend<&'a str> for String<'bump> {
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = &'a str>,
+    {
+        let buffers = collect_string_buffers(par_iter, |buf, s| buf.push_str(s));
+        self.extend_from_buffers(buffers);
+    }
+}
+
+impl<'bump> ParallelExtend<::std::string::String> for String<'bump> {
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = ::std::string::String>,
+    {
+        let buffers = collect_string_buffers(par_iter, |buf, s| buf.push_str(&s));
+        self.extend_from_buffers(buffers);
+    }
+}
@@ -0,0 +1,274 @@
+//! Extension traits for building and splitting strings in an arena without
+//! temporary heap allocations.
+
+use crate::collections::Vec;
+use crate::Bump;
+use core::alloc::Layout;
+use core::ptr;
+use core::str;
+use std::slice;
+
+/// Joining and concatenating slices of strings into a single string allocated
+/// in an arena.
+///
+/// The length of the result is computed up front, so the result is allocated
+/// with exactly one bump allocation of exactly the right size.
+///
+/// ## Example
+///
+/// ```
+/// use bumpalo::{Bump, collections::JoinIn};
+///
+/// let b = Bump::new();
+///
+/// let words = ["hello", "bump", "world"];
+/// assert_eq!(words.join_in(", ", &b), "hello, bump, world");
+/// assert_eq!(words.concat_in(&b), "hellobumpworld");
+/// ```
+pub trait JoinIn {
+    /// Flattens this slice of strings into a single string allocated in
+    /// `bump`, placing `sep` between each of them.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the length of the result overflows, or if one of the
+    /// strings' `as_ref` returns strings of different lengths when called
+    /// more than once.
+    fn join_in<'bump>(&self, sep: &str, bump: &'bump Bump) -> &'bump str;
+
+    /// Flattens this slice of strings into a single string allocated in
+    /// `bump`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the length of the result overflows, or if one of the
+    /// strings' `as_ref` returns strings of different lengths when called
+    /// more than once.
+    fn concat_in<'bump>(&self, bump: &'bump Bump) -> &'bump str {
+        self.join_in("", bump)
+    }
+}
+
+impl<S: AsRef<str>> JoinIn for [S] {
+    fn join_in<'bump>(&self, sep: &str, bump: &'bump Bump) -> &'bump str {
+        let len = self
+            .iter()
+            .try_fold(0_usize, |len, s| len.checked_add(s.as_ref().len()))
+            .and_then(|len| {
+                let seps = self.len().saturating_sub(1);
+                len.checked_add(sep.len().checked_mul(seps)?)
+            })
+            .expect("length overflow");
+        let layout = Layout::from_size_align(len, 1).expect("length overflow");
+
+        unsafe {
+            let dst = bump.alloc_layout(layout).as_ptr();
+            let mut written = 0;
+            let mut push = |s: &str| {
+                // Don't trust `as_ref` to return the same string every time.
+                assert!(
+                    s.len() <= len - written,
+                    "inconsistent `AsRef<str>` implementation"
+                );
+                ptr::copy_nonoverlapping(s.as_ptr(), dst.add(written), s.len());
+                written += s.len();
+            };
+
+            for (i, s) in self.iter().enumerate() {
+                if i > 0 {
+                    push(sep);
+                }
+                push(s.as_ref());
+            }
+
+            assert_eq!(written, len, "inconsistent `AsRef<str>` implementation");
+            str::from_utf8_unchecked(slice::from_raw_parts(dst, len))
+        }
+    }
+}
+
+/// Splitting a string into a `Vec` of substrings allocated in an arena.
+///
+/// The substrings borrow from the input string; only the `Vec` holding them
+/// is allocated in the arena. The number of substrings is counted up front, so
+/// the `Vec` is allocated with exactly one bump allocation of exactly the
+/// right size.
+///
+/// ## Example
+///
+/// ```
+/// use bumpalo::{Bump, collections::SplitIn};
+///
+/// let b = Bump::new();
+///
+/// let line = "a,b,,c";
+/// let fields = line.split_in(",", &b);
+/// assert_eq!(fields, ["a", "b", "", "c"]);
+/// assert_eq!(fields.capacity(), 4);
+/// ```
+pub trait SplitIn {
+    /// Splits this string by `pat`, like
+    /// [`str::split`](https://doc.rust-lang.org/nightly/std/primitive.str.html#method.split),
+    /// and collects the pieces into a `Vec` allocated in `bump`.
+    fn split_in<'a, 'bump>(&'a self, pat: &str, bump: &'bump Bump) -> Vec<'bump, &'a str>;
+
+    /// Splits this string into lines, like
+    /// [`str::lines`](https://doc.rust-lang.org/nightly/std/primitive.str.html#method.lines),
+    /// and collects them into a `Vec` allocated in `bump`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::SplitIn};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let text = "foo\r\nbar\n\nbaz\n";
+    /// assert_eq!(text.lines_in(&b), ["foo", "bar", "", "baz"]);
+    /// ```
+    fn lines_in<'a, 'bump>(&'a self, bump: &'bump Bump) -> Vec<'bump, &'a str>;
+
+    /// Splits this string by whitespace, like
+    /// [`str::split_whitespace`](https://doc.rust-lang.org/nightly/std/primitive.str.html#method.split_whitespace),
+    /// and collects the pieces into a `Vec` allocated in `bump`.
+    ///
+    /// Leading and trailing whitespace is trimmed, and runs of whitespace
+    /// count as a single separator.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::SplitIn};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let text = "  Mary   had\ta little  \n\t lamb ";
+    /// assert_eq!(text.split_whitespace_in(&b), ["Mary", "had", "a", "little", "lamb"]);
+    /// ```
+    fn split_whitespace_in<'a, 'bump>(&'a self, bump: &'bump Bump) -> Vec<'bump, &'a str>;
+}
+
+impl SplitIn for str {
+    fn split_in<'a, 'bump>(&'a self, pat: &str, bump: &'bump Bump) -> Vec<'bump, &'a str> {
+        collect_exact(self.split(pat), bump)
+    }
+
+    fn lines_in<'a, 'bump>(&'a self, bump: &'bump Bump) -> Vec<'bump, &'a str> {
+        collect_exact(self.lines(), bump)
+    }
+
+    fn split_whitespace_in<'a, 'bump>(&'a self, bump: &'bump Bump) -> Vec<'bump, &'a str> {
+        collect_exact(self.split_whitespace(), bump)
+    }
+}
+
+/// Trimming whitespace out of a string, borrowing from the input when the
+/// result is a substring of it, and allocating the result in an arena only
+/// when it isn't.
+///
+/// The result lives as long as the shorter of the input and the arena. When
+/// the result has to be allocated, its length is computed up front, so it is
+/// allocated with exactly one bump allocation of exactly the right size.
+///
+/// ## Example
+///
+/// ```
+/// use bumpalo::{Bump, collections::TrimIn};
+///
+/// let b = Bump::new();
+///
+/// // Already tidy once the ends are trimmed: borrows from the input.
+/// let text = "  hello world\n";
+/// let s = text.squish_in(&b);
+/// assert_eq!(s, "hello world");
+/// assert!(!b.contains(s.as_ptr()));
+///
+/// // The inner whitespace needs collapsing: allocates in the arena.
+/// let s = "hello \t  world".squish_in(&b);
+/// assert_eq!(s, "hello world");
+/// assert!(b.contains(s.as_ptr()));
+/// ```
+pub trait TrimIn {
+    /// Trims leading and trailing whitespace, and collapses every run of
+    /// whitespace inside the string into a single space.
+    fn squish_in<'a>(&'a self, bump: &'a Bump) -> &'a str;
+
+    /// Trims leading and trailing whitespace from the string and from each of
+    /// its lines, and joins the lines back together with `\n`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::TrimIn};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let text = "\n  first  \r\n\t second\n";
+    /// assert_eq!(text.trim_lines_in(&b), "first\nsecond");
+    /// ```
+    fn trim_lines_in<'a>(&'a self, bump: &'a Bump) -> &'a str;
+}
+
+impl TrimIn for str {
+    fn squish_in<'a>(&'a self, bump: &'a Bump) -> &'a str {
+        let trimmed = self.trim();
+        let tidy = trimmed
+            .split(' ')
+            .all(|word| !word.is_empty() && !word.contains(char::is_whitespace));
+        if trimmed.is_empty() || tidy {
+            return trimmed;
+        }
+        join_exact(trimmed.split_whitespace(), " ", bump)
+    }
+
+    fn trim_lines_in<'a>(&'a self, bump: &'a Bump) -> &'a str {
+        let trimmed = self.trim();
+        let tidy =
+            !trimmed.contains('\r') && trimmed.lines().all(|line| line.trim().len() == line.len());
+        if tidy {
+            return trimmed;
+        }
+        join_exact(trimmed.lines().map(str::trim), "\n", bump)
+    }
+}
+
+// Join `pieces` with `sep` into a string with exactly one bump allocation of
+// exactly the right size, by running a clone of `pieces` to measure them first.
+fn join_exact<'a, 'bump, I>(pieces: I, sep: &str, bump: &'bump Bump) -> &'bump str
+where
+    I: Iterator<Item = &'a str> + Clone,
+{
+    let (count, len) = pieces
+        .clone()
+        .fold((0_usize, 0_usize), |(count, len), s| (count + 1, len + s.len()));
+    let len = len + sep.len() * count.saturating_sub(1);
+    let layout = Layout::from_size_align(len, 1).expect("length overflow");
+
+    unsafe {
+        let dst = bump.alloc_layout(layout).as_ptr();
+        let mut written = 0;
+        let mut push = |s: &str| {
+            ptr::copy_nonoverlapping(s.as_ptr(), dst.add(written), s.len());
+            written += s.len();
+        };
+        for (i, s) in pieces.enumerate() {
+            if i > 0 {
+                push(sep);
+            }
+            push(s);
+        }
+        debug_assert_eq!(written, len);
+        str::from_utf8_unchecked(slice::from_raw_parts(dst, len))
+    }
+}
+
+// Collect `iter` into a `Vec` with exactly enough capacity for its items, by
+// running a clone of it to count them first.
+fn collect_exact<'bump, I>(iter: I, bump: &'bump Bump) -> Vec<'bump, I::Item>
+where
+    I: Iterator + Clone,
+{
+    let mut v = Vec::with_capacity_in(iter.clone().count(), bump);
+    v.extend(iter);
+    v
+}
@@ -60,7 +60,7 @@
 //! ```
 
 use super::str::lossy;
-use core::char::decode_utf16;
+use core::char::{decode_utf16, REPLACEMENT_CHARACTER};
 use core::fmt;
 use core::hash;
 use core::iter::FusedIterator;
@@ -68,15 +68,20 @@ use core::mem;
 use core::ops::Bound::{Excluded, Included, Unbounded};
 use core::ops::{self, Add, AddAssign, Index, IndexMut, RangeBounds};
 use core::ptr;
-use crate::Bump;
+use crate::{Bump, CloneIn};
 
 use crate::collections::vec::Vec;
+use crate::collections::{CollectionAllocErr, FromIteratorIn};
 use std::borrow::Cow;
 use std::str::{self, Chars, Utf8Error};
 
 /// Like the `format!` macro for creating `std::string::String`s but for
 /// `bumpalo::collections::String`.
 ///
+/// Everything after the `in $bump,` prefix is passed on to
+/// [`format_args!`](https://doc.rust-lang.org/nightly/std/macro.format_args.html),
+/// so this accepts all of the same forms as `std::format!`.
+///
 /// # Examples
 ///
 /// ```
@@ -86,17 +91,19 @@ use std::str::{self, Chars, Utf8Error};
 ///
 /// let who = "World";
 /// let s = bumpalo::format!(in &b, "Hello, {}!", who);
-/// assert_eq!(s, "Hello, World!")
+/// assert_eq!(s, "Hello, World!");
+///
+/// let s = bumpalo::format!(in &b, "Hello, {who}! {n:>3}", n = 7,);
+/// assert_eq!(s, "Hello, World!   7");
 /// ```
 #[macro_export]
 macro_rules! format {
-    ( in $bump:expr, $fmt:expr, $($args:expr),* ) => {{
-        use std::fmt::Write;
-        let bump = $bump;
-        let mut s = $crate::collections::String::new_in(bump);
-        let _ = write!(&mut s, $fmt, $($args),*);
-        s
-    }}
+    ( in $bump:expr, $($arg:tt)* ) => {
+        $crate::collections::ToStringIn::to_string_in(
+            &$crate::__private::format_args!($($arg)*),
+            $bump,
+        )
+    }
 }
 
 /// A UTF-8 encoded, growable string.
@@ -662,6 +669,43 @@ impl<'bump> String<'bump> {
         res
     }
 
+    /// Converts a vector of bytes to a `String`, including invalid characters.
+    ///
+    /// This is like [`from_utf8_lossy_in`], but takes ownership of the bytes.
+    /// If they are valid UTF-8, the vector's allocation is reused instead of
+    /// being copied. Otherwise, a new `String` is allocated in the same arena,
+    /// with invalid sequences replaced by
+    /// [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+    ///
+    /// [`from_utf8_lossy_in`]: #method.from_utf8_lossy_in
+    /// [U+FFFD]: https://doc.rust-lang.org/nightly/std/char/constant.REPLACEMENT_CHARACTER.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bumpalo::{collections::String, Bump};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let sparkle_heart = bumpalo::vec![in &b; 240, 159, 146, 150];
+    /// let ptr = sparkle_heart.as_ptr();
+    ///
+    /// let sparkle_heart = String::from_utf8_lossy(sparkle_heart);
+    /// assert_eq!("💖", sparkle_heart);
+    /// assert_eq!(ptr, sparkle_heart.as_ptr());
+    ///
+    /// let input = bumpalo::vec![in &b; b'H', b'i', 0xF0, 0x90, 0x80, b'!'];
+    /// assert_eq!("Hi�!", String::from_utf8_lossy(input));
+    /// ```
+    pub fn from_utf8_lossy(v: Vec<'bump, u8>) -> String<'bump> {
+        match str::from_utf8(&v) {
+            Ok(_) => unsafe { String::from_utf8_unchecked(v) },
+            Err(_) => String::from_utf8_lossy_in(&v, v.bump()),
+        }
+    }
+
     /// Decode a UTF-16 encoded vector `v` into a `String`, returning [`Err`]
     /// if `v` contains any invalid data.
     ///
@@ -699,6 +743,36 @@ impl<'bump> String<'bump> {
         Ok(ret)
     }
 
+    /// Decode a UTF-16 encoded slice `v` into a `String`, replacing
+    /// invalid data with [the replacement character (`U+FFFD`)][U+FFFD].
+    ///
+    /// [U+FFFD]: https://doc.rust-lang.org/nightly/std/char/constant.REPLACEMENT_CHARACTER.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::String};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// // 𝄞mus<invalid>ic<invalid>
+    /// let v = &[0xD834, 0xDD1E, 0x006d, 0x0075,
+    ///           0x0073, 0xDD1E, 0x0069, 0x0063,
+    ///           0xD834];
+    ///
+    /// assert_eq!(String::from_str_in("𝄞mus\u{FFFD}ic\u{FFFD}", &b),
+    ///            String::from_utf16_lossy_in(v, &b));
+    /// ```
+    pub fn from_utf16_lossy_in(v: &[u16], bump: &'bump Bump) -> String<'bump> {
+        let mut ret = String::with_capacity_in(v.len(), bump);
+        for c in decode_utf16(v.iter().cloned()) {
+            ret.push(c.unwrap_or(REPLACEMENT_CHARACTER));
+        }
+        ret
+    }
+
     /// Construct a new `String<'bump>` from an iterator of `char`s.
     ///
     /// # Examples
@@ -731,9 +805,7 @@ impl<'bump> String<'bump> {
     /// ```
     pub fn from_iter_in<I: IntoIterator<Item = char>>(iter: I, bump: &'bump Bump) -> String<'bump> {
         let mut s = String::new_in(bump);
-        for c in iter {
-            s.push(c);
-        }
+        s.extend(iter);
         s
     }
 
@@ -941,6 +1013,34 @@ impl<'bump> String<'bump> {
         self.vec.extend_from_slice(string.as_bytes())
     }
 
+    /// Appends a given string slice onto the end of this `String`, like
+    /// [`push_str`], but returns an error instead of panicking if the capacity
+    /// overflows or the arena fails to allocate.
+    ///
+    /// On error, this `String` is left unchanged.
+    ///
+    /// [`push_str`]: #method.push_str
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::String};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let mut s = String::from_str_in("foo", &b);
+    ///
+    /// s.try_push_str("bar").unwrap();
+    ///
+    /// assert_eq!("foobar", s);
+    /// ```
+    #[inline]
+    pub fn try_push_str(&mut self, string: &str) -> Result<(), CollectionAllocErr> {
+        self.vec.try_extend_from_slice(string.as_bytes())
+    }
+
     /// Returns this `String`'s capacity, in bytes.
     ///
     /// # Examples
@@ -1674,6 +1774,203 @@ impl<'bump> String<'bump> {
     }
 }
 
+impl<'bump> String<'bump> {
+    /// Returns the lowercase equivalent of this string, as a new `String`
+    /// allocated in `bump`.
+    ///
+    /// 'Lowercase' is defined according to the terms of the Unicode Derived
+    /// Core Property `Lowercase`, exactly like
+    /// [`str::to_lowercase`](https://doc.rust-lang.org/nightly/std/primitive.str.html#method.to_lowercase).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::String};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let s = String::from_str_in("HELLO", &b);
+    /// assert_eq!("hello", s.to_lowercase_in(&b));
+    ///
+    /// let odysseus = String::from_str_in("ὈΔΥΣΣΕΎΣ", &b);
+    /// assert_eq!("ὀδυσσεύς", odysseus.to_lowercase_in(&b));
+    /// ```
+    pub fn to_lowercase_in<'new>(&self, bump: &'new Bump) -> String<'new> {
+        // Whether a capital sigma lowercases to a final sigma depends on the
+        // case properties of the characters around it, which are not exposed
+        // by `core`. Leave that rare case to `std`.
+        if self.contains('Σ') {
+            return String::from_str_in(&self.as_str().to_lowercase(), bump);
+        }
+
+        let mut s = String::with_capacity_in(self.len(), bump);
+        for c in self.chars() {
+            s.extend(c.to_lowercase());
+        }
+        s
+    }
+
+    /// Returns the uppercase equivalent of this string, as a new `String`
+    /// allocated in `bump`.
+    ///
+    /// 'Uppercase' is defined according to the terms of the Unicode Derived
+    /// Core Property `Uppercase`, exactly like
+    /// [`str::to_uppercase`](https://doc.rust-lang.org/nightly/std/primitive.str.html#method.to_uppercase).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::String};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let s = String::from_str_in("tschüß", &b);
+    /// assert_eq!("TSCHÜSS", s.to_uppercase_in(&b));
+    /// ```
+    pub fn to_uppercase_in<'new>(&self, bump: &'new Bump) -> String<'new> {
+        let mut s = String::with_capacity_in(self.len(), bump);
+        for c in self.chars() {
+            s.extend(c.to_uppercase());
+        }
+        s
+    }
+
+    /// Replaces all matches of `from` with `to`, returning the result as a new
+    /// `String` allocated in `bump`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::String};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let s = String::from_str_in("this is old", &b);
+    /// assert_eq!("this is new", s.replace_in("old", "new", &b));
+    /// assert_eq!("than an old", s.replace_in("is", "an", &b));
+    /// ```
+    pub fn replace_in<'new>(&self, from: &str, to: &str, bump: &'new Bump) -> String<'new> {
+        self.replacen_in(from, to, usize::MAX, bump)
+    }
+
+    /// Replaces the first `count` matches of `pat` with `to`, returning the
+    /// result as a new `String` allocated in `bump`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::String};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let s = String::from_str_in("foo foo 123 foo", &b);
+    /// assert_eq!("new new 123 foo", s.replacen_in("foo", "new", 2, &b));
+    /// assert_eq!("foo foo 123 foo", s.replacen_in("bar", "new", 2, &b));
+    /// ```
+    pub fn replacen_in<'new>(
+        &self,
+        pat: &str,
+        to: &str,
+        count: usize,
+        bump: &'new Bump,
+    ) -> String<'new> {
+        let mut result = String::with_capacity_in(self.len(), bump);
+        let mut last_end = 0;
+        for (start, part) in self.match_indices(pat).take(count) {
+            result.push_str(unsafe { self.get_unchecked(last_end..start) });
+            result.push_str(to);
+            last_end = start + part.len();
+        }
+        result.push_str(unsafe { self.get_unchecked(last_end..self.len()) });
+        result
+    }
+
+    /// Creates a new `String` in `bump` by repeating this string `n` times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity would overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::String};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let s = String::from_str_in("abc", &b);
+    /// assert_eq!("abcabcabcabc", s.repeat_in(4, &b));
+    /// ```
+    pub fn repeat_in<'new>(&self, n: usize, bump: &'new Bump) -> String<'new> {
+        let capacity = self.len().checked_mul(n).expect("capacity overflow");
+        let mut s = String::with_capacity_in(capacity, bump);
+        for _ in 0..n {
+            s.push_str(self);
+        }
+        s
+    }
+
+    /// Encodes this string as UTF-16 into a new `Vec` allocated in `bump`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::String};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let s = String::from_str_in("𝄞music", &b);
+    /// let utf16 = s.encode_utf16_in(&b);
+    /// assert_eq!(utf16, [0xD834, 0xDD1E, 0x006d, 0x0075, 0x0073, 0x0069, 0x0063]);
+    /// assert_eq!(String::from_utf16_in(&utf16, &b).unwrap(), s);
+    /// ```
+    pub fn encode_utf16_in<'new>(&self, bump: &'new Bump) -> Vec<'new, u16> {
+        let mut v = Vec::with_capacity_in(self.len(), bump);
+        v.extend(self.encode_utf16());
+        v
+    }
+
+    /// Escapes each char in this string with
+    /// [`char::escape_debug`](https://doc.rust-lang.org/nightly/std/primitive.char.html#method.escape_debug),
+    /// and returns the result as a new `String` allocated in `bump`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::String};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let s = String::from_str_in("❤\n!", &b);
+    /// assert_eq!("❤\\n!", s.escape_debug_in(&b));
+    /// ```
+    pub fn escape_debug_in<'new>(&self, bump: &'new Bump) -> String<'new> {
+        let mut s = String::with_capacity_in(self.len(), bump);
+        s.extend(self.escape_debug());
+        s
+    }
+
+    /// Escapes each char in this string with
+    /// [`char::escape_default`](https://doc.rust-lang.org/nightly/std/primitive.char.html#method.escape_default),
+    /// and returns the result as a new `String` allocated in `bump`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::String};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let s = String::from_str_in("❤\n!", &b);
+    /// assert_eq!("\\u{2764}\\n!", s.escape_default_in(&b));
+    /// ```
+    pub fn escape_default_in<'new>(&self, bump: &'new Bump) -> String<'new> {
+        let mut s = String::with_capacity_in(self.len(), bump);
+        s.extend(self.escape_default());
+        s
+    }
+}
+
 impl<'bump> FromUtf8Error<'bump> {
     /// Returns a slice of [`u8`]s bytes that were attempted to convert to a `String`.
     ///
@@ -1830,6 +2127,45 @@ impl<'a, 'bump> Extend<Cow<'a, str>> for String<'bump> {
     }
 }
 
+impl<'new> CloneIn<'new> for String<'_> {
+    type Cloned = String<'new>;
+
+    #[inline]
+    fn clone_in(&self, bump: &'new Bump) -> String<'new> {
+        String::from_str_in(self, bump)
+    }
+}
+
+impl<'bump> FromIteratorIn<'bump, char> for String<'bump> {
+    #[inline]
+    fn from_iter_in<I: IntoIterator<Item = char>>(iter: I, bump: &'bump Bump) -> String<'bump> {
+        String::from_iter_in(iter, bump)
+    }
+}
+
+impl<'a, 'bump> FromIteratorIn<'bump, &'a str> for String<'bump> {
+    fn from_iter_in<I: IntoIterator<Item = &'a str>>(iter: I, bump: &'bump Bump) -> String<'bump> {
+        let iter = iter.into_iter();
+        let mut s = String::with_capacity_in(iter.size_hint().0, bump);
+        s.extend(iter);
+        s
+    }
+}
+
+impl<'a, 'bump> FromIteratorIn<'bump, String<'a>> for String<'bump> {
+    fn from_iter_in<I>(iter: I, bump: &'bump Bump) -> String<'bump>
+    where
+        I: IntoIterator<Item = String<'a>>,
+    {
+        let iter = iter.into_iter();
+        let mut s = String::with_capacity_in(iter.size_hint().0, bump);
+        for t in iter {
+            s.push_str(&t);
+        }
+        s
+    }
+}
+
 impl<'bump> PartialEq for String<'bump> {
     #[inline]
     fn eq(&self, other: &String) -> bool {
@@ -1893,6 +2229,44 @@ impl<'bump> hash::Hash for String<'bump> {
     }
 }
 
+/// A trait for converting a value to a bump-allocated `String`.
+///
+/// This is the bump-allocated counterpart of `std`'s `ToString`, and is
+/// implemented for every type that implements [`Display`]. The text is
+/// formatted with [`Bump::alloc_fmt`], so the returned `String`'s capacity is
+/// exactly its length.
+///
+/// [`Display`]: https://doc.rust-lang.org/nightly/std/fmt/trait.Display.html
+/// [`Bump::alloc_fmt`]: ../struct.Bump.html#method.alloc_fmt
+///
+/// # Examples
+///
+/// ```
+/// use bumpalo::{Bump, collections::ToStringIn};
+///
+/// let b = Bump::new();
+///
+/// let five = 5.to_string_in(&b);
+/// assert_eq!(five, "5");
+/// assert_eq!(five.capacity(), 1);
+/// ```
+pub trait ToStringIn {
+    /// Converts the given value to a `String` allocated in `bump`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value's `Display` implementation returns an error.
+    fn to_string_in<'bump>(&self, bump: &'bump Bump) -> String<'bump>;
+}
+
+impl<T: fmt::Display + ?Sized> ToStringIn for T {
+    #[inline]
+    fn to_string_in<'bump>(&self, bump: &'bump Bump) -> String<'bump> {
+        let s = bump.alloc_fmt(format_args!("{}", self));
+        unsafe { String::from_raw_parts_in(s.as_mut_ptr(), s.len(), s.len(), bump) }
+    }
+}
+
 /// Implements the `+` operator for concatenating two strings.
 ///
 /// This consumes the `String<'bump>` on the left-hand side and re-uses its buffer (growing it if
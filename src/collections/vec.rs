@@ -84,6 +84,7 @@
 //! [`vec!`]: ../../macro.vec.html
 
 use super::raw_vec::RawVec;
+use crate::collections::{CollectionAllocErr, FromIteratorIn};
 use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{self, Hash};
@@ -96,7 +97,7 @@ use core::ops::{Index, IndexMut, RangeBounds};
 use core::ptr;
 use core::ptr::NonNull;
 use std::slice;
-use crate::Bump;
+use crate::{Bump, CloneIn};
 
 unsafe fn arith_offset<T>(p: *const T, offset: isize) -> *const T {
     p.offset(offset)
@@ -205,10 +206,31 @@ where
     d / (pointee_size as isize)
 }
 
+// Resolve `range` against a vector of length `len`, into a `start..end` pair
+// of indices. Panics if the range is decreasing or out of bounds.
+fn range_to_bounds<R>(range: R, len: usize) -> (usize, usize)
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Included(&n) => n,
+        Excluded(&n) => n + 1,
+        Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Included(&n) => n + 1,
+        Excluded(&n) => n,
+        Unbounded => len,
+    };
+    assert!(start <= end);
+    assert!(end <= len);
+    (start, end)
+}
+
 /// Creates a [`Vec`] containing the arguments.
 ///
 /// `vec!` allows `Vec`s to be defined with the same syntax as array expressions.
-/// There are two forms of this macro:
+/// There are three forms of this macro:
 ///
 /// - Create a [`Vec`] containing a given list of elements:
 ///
@@ -232,6 +254,20 @@ where
 /// assert_eq!(v, [1, 1, 1]);
 /// ```
 ///
+/// - Create an empty [`Vec`]:
+///
+/// ```
+/// use bumpalo::{Bump, collections::Vec};
+///
+/// let b = Bump::new();
+/// let v: Vec<i32> = bumpalo::vec![in &b];
+/// assert!(v.is_empty());
+/// ```
+///
+/// Every form accepts a trailing comma. The first two allocate exactly as much
+/// capacity as they need up front: a list of elements is moved into the arena
+/// as a whole, without pushing the elements one at a time.
+///
 /// Note that unlike array expressions this syntax supports all elements
 /// which implement [`Clone`] and the number of elements doesn't have to be
 /// a constant.
@@ -242,29 +278,52 @@ where
 /// to the same boxed integer value, not five references pointing to independently
 /// boxed integers.
 ///
+/// To clone the elements of an existing slice into the arena, use
+/// [`vec_from_slice!`].
+///
 /// [`Vec`]: ../collections/vec/struct.Vec.html
+/// [`vec_from_slice!`]: macro.vec_from_slice.html
 /// [`Clone`]: https://doc.rust-lang.org/nightly/std/clone/trait.Clone.html
 #[macro_export]
 macro_rules! vec {
-    (in $bump:expr; $elem:expr; $n:expr) => {{
-        let n = $n;
-        let mut v = $crate::collections::Vec::with_capacity_in(n, $bump);
-        if n > 0 {
-            let elem = $elem;
-            for _ in 0..n - 1 {
-                v.push(elem.clone());
-            }
-            v.push(elem);
-        }
-        v
-    }};
-    (in $bump:expr) => { $crate::collections::Vec::new_in($bump) };
-    (in $bump:expr; $($x:expr),*) => {{
-        let mut v = $crate::collections::Vec::new_in($bump);
-        $( v.push($x); )*
-        v
-    }};
-    (in $bump:expr; $(, $x:expr,)*) => (bumpalo::vec![in $bump; $($x),*])
+    (in $bump:expr; $elem:expr; $n:expr $(,)?) => {
+        $crate::collections::vec::from_elem_in($elem, $n, $bump)
+    };
+    (in $bump:expr $(;)? $(,)?) => { $crate::collections::Vec::new_in($bump) };
+    (in $bump:expr; $($x:expr),+ $(,)?) => {
+        $crate::collections::Vec::from_array_in([$($x),+], $bump)
+    };
+}
+
+/// Creates a [`Vec`] holding a clone of each element of a slice, array, or
+/// vector, with exactly the capacity it needs, like [`Vec::from_slice_in`].
+///
+/// To copy the elements of a slice of [`Copy`] values in one go, use
+/// [`Vec::from_slice_copy_in`] instead.
+///
+/// ```
+/// use bumpalo::Bump;
+///
+/// let b = Bump::new();
+/// let numbers = [1, 2, 3];
+/// let v = bumpalo::vec_from_slice![in &b; numbers];
+/// assert_eq!(v, [1, 2, 3]);
+/// assert_eq!(v.capacity(), 3);
+///
+/// let words = vec![String::from("hello"), String::from("world")];
+/// let v = bumpalo::vec_from_slice![in &b; words];
+/// assert_eq!(v, &words[..]);
+/// ```
+///
+/// [`Vec`]: ../collections/vec/struct.Vec.html
+/// [`Vec::from_slice_in`]: ../collections/vec/struct.Vec.html#method.from_slice_in
+/// [`Vec::from_slice_copy_in`]: ../collections/vec/struct.Vec.html#method.from_slice_copy_in
+/// [`Copy`]: https://doc.rust-lang.org/nightly/std/marker/trait.Copy.html
+#[macro_export]
+macro_rules! vec_from_slice {
+    (in $bump:expr; $slice:expr $(,)?) => {
+        $crate::collections::Vec::from_slice_in(&$slice[..], $bump)
+    };
 }
 
 /// A contiguous growable array type, written `Vec<'bump, T>` but pronounced 'vector'.
@@ -583,6 +642,36 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         }
     }
 
+    /// Constructs a new, empty `Vec<'bump, T>` with the specified capacity,
+    /// like [`with_capacity_in`], but returns an error instead of panicking if
+    /// the capacity overflows or the arena fails to allocate.
+    ///
+    /// [`with_capacity_in`]: #method.with_capacity_in
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::{CollectionAllocErr, Vec}};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let vec = Vec::<u32>::try_with_capacity_in(10, &b).unwrap();
+    /// assert!(vec.capacity() >= 10);
+    ///
+    /// let err = Vec::<u32>::try_with_capacity_in(usize::MAX, &b).unwrap_err();
+    /// assert_eq!(err, CollectionAllocErr::CapacityOverflow);
+    /// ```
+    #[inline]
+    pub fn try_with_capacity_in(
+        capacity: usize,
+        bump: &'bump Bump,
+    ) -> Result<Vec<'bump, T>, CollectionAllocErr> {
+        Ok(Vec {
+            buf: RawVec::try_with_capacity_in(capacity, bump)?,
+            len: 0,
+        })
+    }
+
     /// Construct a new `Vec` from the given iterator's items.
     ///
     /// # Examples
@@ -601,6 +690,82 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         v
     }
 
+    /// Construct a new `Vec` by moving the elements of an array into the
+    /// arena, with exactly the capacity they need.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    /// let v = Vec::from_array_in([1, 2, 3], &b);
+    /// assert_eq!(v, [1, 2, 3]);
+    /// assert_eq!(v.capacity(), 3);
+    /// ```
+    pub fn from_array_in<const N: usize>(array: [T; N], bump: &'bump Bump) -> Vec<'bump, T> {
+        let mut v = Vec::with_capacity_in(N, bump);
+        let array = mem::ManuallyDrop::new(array);
+        unsafe {
+            ptr::copy_nonoverlapping(array.as_ptr(), v.as_mut_ptr(), N);
+            v.set_len(N);
+        }
+        v
+    }
+
+    /// Construct a new `Vec` by cloning the elements of a slice into the
+    /// arena, with exactly the capacity they need.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    /// let words = ["hello", "world"];
+    /// let v = Vec::from_slice_in(&words[..], &b);
+    /// assert_eq!(v, words);
+    /// assert_eq!(v.capacity(), 2);
+    /// ```
+    pub fn from_slice_in(slice: &[T], bump: &'bump Bump) -> Vec<'bump, T>
+    where
+        T: Clone,
+    {
+        let mut v = Vec::with_capacity_in(slice.len(), bump);
+        v.extend_from_slice(slice);
+        v
+    }
+
+    /// Construct a new `Vec` by copying the elements of a slice into the
+    /// arena, with exactly the capacity they need.
+    ///
+    /// This is like [`from_slice_in`], but copies all of the elements at once
+    /// instead of cloning them one at a time.
+    ///
+    /// [`from_slice_in`]: #method.from_slice_in
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    /// let v = Vec::from_slice_copy_in(&[1, 2, 3][..], &b);
+    /// assert_eq!(v, [1, 2, 3]);
+    /// assert_eq!(v.capacity(), 3);
+    /// ```
+    pub fn from_slice_copy_in(slice: &[T], bump: &'bump Bump) -> Vec<'bump, T>
+    where
+        T: Copy,
+    {
+        let mut v = Vec::with_capacity_in(slice.len(), bump);
+        unsafe {
+            ptr::copy_nonoverlapping(slice.as_ptr(), v.as_mut_ptr(), slice.len());
+            v.set_len(slice.len());
+        }
+        v
+    }
+
     /// Creates a `Vec<'bump, T>` directly from the raw components of another vector.
     ///
     /// # Safety
@@ -672,6 +837,12 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         }
     }
 
+    /// Returns the arena that this vector is allocated in.
+    #[inline]
+    pub(crate) fn bump(&self) -> &'bump Bump {
+        self.buf.bump()
+    }
+
     /// Returns the number of elements the vector can hold without
     /// reallocating.
     ///
@@ -740,6 +911,62 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         self.buf.reserve_exact(self.len, additional);
     }
 
+    /// Tries to reserve capacity for at least `additional` more elements to be
+    /// inserted in the given `Vec<'bump, T>`. The collection may reserve more
+    /// space to avoid frequent reallocations. After calling `try_reserve`,
+    /// capacity will be greater than or equal to `self.len() + additional`.
+    /// Does nothing if capacity is already sufficient.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the arena fails to allocate, then an
+    /// error is returned and the vector is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    /// let mut vec = bumpalo::vec![in &b; 1];
+    /// vec.try_reserve(10).unwrap();
+    /// assert!(vec.capacity() >= 11);
+    /// assert!(vec.try_reserve(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionAllocErr> {
+        self.buf.try_reserve(self.len, additional)
+    }
+
+    /// Tries to reserve the minimum capacity for exactly `additional` more
+    /// elements to be inserted in the given `Vec<'bump, T>`. After calling
+    /// `try_reserve_exact`, capacity will be greater than or equal to
+    /// `self.len() + additional`. Does nothing if the capacity is already
+    /// sufficient.
+    ///
+    /// Note that the allocator may give the collection more space than it
+    /// requests. Therefore capacity can not be relied upon to be precisely
+    /// minimal. Prefer `try_reserve` if future insertions are expected.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the arena fails to allocate, then an
+    /// error is returned and the vector is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    /// let mut vec = bumpalo::vec![in &b; 1];
+    /// vec.try_reserve_exact(10).unwrap();
+    /// assert!(vec.capacity() >= 11);
+    /// assert!(vec.try_reserve_exact(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionAllocErr> {
+        self.buf.try_reserve_exact(self.len, additional)
+    }
+
     /// Shrinks the capacity of the vector as much as possible.
     ///
     /// It will drop down as close as possible to the length but the allocator
@@ -786,6 +1013,70 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         }
     }
 
+    /// Converts the vector into `&'bump mut [T]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    /// let v = bumpalo::vec![in &b; 3, 1, 2];
+    ///
+    /// let slice = v.into_bump_slice_mut();
+    /// slice.sort();
+    /// assert_eq!(slice, [1, 2, 3]);
+    /// ```
+    pub fn into_bump_slice_mut(mut self) -> &'bump mut [T] {
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            let len = self.len();
+            mem::forget(self);
+            slice::from_raw_parts_mut(ptr, len)
+        }
+    }
+
+    /// Decomposes a `Vec<'bump, T>` into its raw components.
+    ///
+    /// Returns the raw pointer to the underlying data, the length of the
+    /// vector (in elements), and the allocated capacity of the data (in
+    /// elements). These are the same arguments in the same order as the
+    /// arguments to [`from_raw_parts_in`].
+    ///
+    /// After calling this function, the caller is responsible for the
+    /// elements previously managed by the `Vec`. The only way to do this is
+    /// to convert the raw pointer, length, and capacity back into a `Vec` with
+    /// [`from_raw_parts_in`], allowing the destructor to perform the cleanup.
+    ///
+    /// [`from_raw_parts_in`]: #method.from_raw_parts_in
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    /// let v = bumpalo::vec![in &b; -1, 0, 1];
+    ///
+    /// let (ptr, len, cap) = v.into_raw_parts();
+    ///
+    /// let rebuilt = unsafe {
+    ///     // We can now make changes to the components, such as
+    ///     // transmuting the raw pointer to a compatible type.
+    ///     let ptr = ptr as *mut u32;
+    ///
+    ///     Vec::from_raw_parts_in(ptr, len, cap, &b)
+    /// };
+    /// assert_eq!(rebuilt, [4294967295, 0, 1]);
+    /// ```
+    pub fn into_raw_parts(mut self) -> (*mut T, usize, usize) {
+        let ptr = self.as_mut_ptr();
+        let len = self.len();
+        let cap = self.capacity();
+        mem::forget(self);
+        (ptr, len, cap)
+    }
+
     /// Shortens the vector, keeping the first `len` elements and dropping
     /// the rest.
     ///
@@ -1105,7 +1396,69 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         self.drain_filter(|x| !f(x));
     }
 
-    fn drain_filter<'a, F>(&'a mut self, filter: F) -> DrainFilter<'a, 'bump, T, F>
+    /// Retains only the elements specified by the predicate, passing a mutable
+    /// reference to it.
+    ///
+    /// In other words, remove all elements `e` such that `f(&mut e)` returns
+    /// `false`. This method operates in place, visiting each element exactly
+    /// once in the original order, and preserves the order of the retained
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let mut vec = bumpalo::vec![in &b; 1, 2, 3, 4];
+    /// vec.retain_mut(|x| if *x <= 3 {
+    ///     *x += 1;
+    ///     true
+    /// } else {
+    ///     false
+    /// });
+    /// assert_eq!(vec, [2, 3, 4]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.drain_filter(|x| !f(x));
+    }
+
+    /// Creates an iterator which uses a closure to determine if an element
+    /// should be removed.
+    ///
+    /// If the closure returns `true`, then the element is removed and yielded.
+    /// If the closure returns `false`, the element will remain in the vector
+    /// and will not be yielded by the iterator.
+    ///
+    /// If the returned `DrainFilter` is dropped before it is exhausted, the
+    /// rest of the vector is still filtered, and the removed elements are
+    /// dropped. Use [`extract_if`] to keep the unvisited elements instead.
+    ///
+    /// [`extract_if`]: #method.extract_if
+    ///
+    /// # Examples
+    ///
+    /// Splitting a vector into evens and odds, reusing the original
+    /// allocation:
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let mut numbers = bumpalo::vec![in &b; 1, 2, 3, 4, 5, 6, 8, 9, 11, 13, 14, 15];
+    ///
+    /// let evens = Vec::from_iter_in(numbers.drain_filter(|x| *x % 2 == 0), &b);
+    /// let odds = numbers;
+    ///
+    /// assert_eq!(evens, [2, 4, 6, 8, 14]);
+    /// assert_eq!(odds, [1, 3, 5, 9, 11, 13, 15]);
+    /// ```
+    pub fn drain_filter<'a, F>(&'a mut self, filter: F) -> DrainFilter<'a, 'bump, T, F>
     where
         F: FnMut(&mut T) -> bool,
     {
@@ -1125,6 +1478,67 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         }
     }
 
+    /// Creates an iterator which uses a closure to determine if an element in
+    /// the range should be removed.
+    ///
+    /// If the closure returns `true`, then the element is removed and yielded.
+    /// If the closure returns `false`, the element will remain in the vector
+    /// and will not be yielded by the iterator.
+    ///
+    /// Only elements that fall in the provided range are considered for
+    /// extraction, but any elements after the range will still have to be
+    /// moved if any element has been extracted.
+    ///
+    /// If the returned `ExtractIf` is not exhausted, e.g. because it is
+    /// dropped without iterating or the iteration short-circuits, then the
+    /// remaining elements will be retained.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let mut items = bumpalo::vec![in &b; 0, 0, 0, 0, 0, 0, 0, 1, 2, 1, 2, 1, 2];
+    /// let ones = Vec::from_iter_in(items.extract_if(7.., |x| *x == 1), &b);
+    /// assert_eq!(items, [0, 0, 0, 0, 0, 0, 0, 2, 2, 2]);
+    /// assert_eq!(ones.len(), 3);
+    ///
+    /// // Stopping early keeps the rest of the elements.
+    /// let mut items = bumpalo::vec![in &b; 1, 2, 3, 4];
+    /// let first_even = items.extract_if(.., |x| *x % 2 == 0).next();
+    /// assert_eq!(first_even, Some(2));
+    /// assert_eq!(items, [1, 3, 4]);
+    /// ```
+    pub fn extract_if<'a, F, R>(&'a mut self, range: R, filter: F) -> ExtractIf<'a, 'bump, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+        R: RangeBounds<usize>,
+    {
+        let old_len = self.len();
+        let (start, end) = range_to_bounds(range, old_len);
+
+        // Guard against the `ExtractIf` getting leaked (leak amplification)
+        unsafe {
+            self.set_len(0);
+        }
+
+        ExtractIf {
+            vec: self,
+            idx: start,
+            end,
+            del: 0,
+            old_len,
+            pred: filter,
+        }
+    }
+
     /// Removes all but the first of consecutive elements in the vector that resolve to the same
     /// key.
     ///
@@ -1216,6 +1630,75 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         }
     }
 
+    /// Appends an element to the back of a collection, like [`push`], but
+    /// returns an error instead of panicking if the capacity overflows or the
+    /// arena fails to allocate.
+    ///
+    /// On error, `value` is dropped and the vector is left unchanged.
+    ///
+    /// [`push`]: #method.push
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let mut vec = bumpalo::vec![in &b; 1, 2];
+    /// vec.try_push(3).unwrap();
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn try_push(&mut self, value: T) -> Result<(), CollectionAllocErr> {
+        if self.len == self.buf.cap() {
+            self.try_reserve(1)?;
+        }
+        unsafe {
+            let end = self.as_mut_ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+        Ok(())
+    }
+
+    /// Appends an element if there is sufficient spare capacity, otherwise an
+    /// error is returned with the element.
+    ///
+    /// Unlike [`push`] this method will not reallocate when there's
+    /// insufficient capacity. The caller should use [`reserve`] or
+    /// [`try_reserve`] to ensure that there is enough capacity.
+    ///
+    /// [`push`]: #method.push
+    /// [`reserve`]: #method.reserve
+    /// [`try_reserve`]: #method.try_reserve
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let mut vec = Vec::with_capacity_in(2, &b);
+    /// assert_eq!(vec.push_within_capacity(1), Ok(()));
+    /// assert_eq!(vec.push_within_capacity(2), Ok(()));
+    /// assert_eq!(vec.push_within_capacity(3), Err(3));
+    /// assert_eq!(vec, [1, 2]);
+    /// ```
+    #[inline]
+    pub fn push_within_capacity(&mut self, value: T) -> Result<(), T> {
+        if self.len == self.buf.cap() {
+            return Err(value);
+        }
+        unsafe {
+            let end = self.as_mut_ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+        Ok(())
+    }
+
     /// Removes the last element from a vector and returns it, or [`None`] if it
     /// is empty.
     ///
@@ -1329,18 +1812,7 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         // the hole, and the vector length is restored to the new length.
         //
         let len = self.len();
-        let start = match range.start_bound() {
-            Included(&n) => n,
-            Excluded(&n) => n + 1,
-            Unbounded => 0,
-        };
-        let end = match range.end_bound() {
-            Included(&n) => n + 1,
-            Excluded(&n) => n,
-            Unbounded => len,
-        };
-        assert!(start <= end);
-        assert!(end <= len);
+        let (start, end) = range_to_bounds(range, len);
 
         unsafe {
             // set self.vec length's to start, to be safe in case Drain is leaked
@@ -1417,6 +1889,95 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         self.len() == 0
     }
 
+    /// Returns the remaining spare capacity of the vector as a slice of
+    /// `MaybeUninit<T>`.
+    ///
+    /// The returned slice can be used to fill the vector with data (e.g. by
+    /// reading from a file) before marking the data as initialized using the
+    /// [`set_len`] method.
+    ///
+    /// [`set_len`]: #method.set_len
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// // Allocate vector big enough for 10 elements.
+    /// let mut v = Vec::with_capacity_in(10, &b);
+    ///
+    /// // Fill in the first 3 elements.
+    /// let uninit = v.spare_capacity_mut();
+    /// uninit[0].write(0);
+    /// uninit[1].write(1);
+    /// uninit[2].write(2);
+    ///
+    /// // Mark the first 3 elements of the vector as being initialized.
+    /// unsafe {
+    ///     v.set_len(3);
+    /// }
+    ///
+    /// assert_eq!(&v, &[0, 1, 2]);
+    /// ```
+    #[inline]
+    pub fn spare_capacity_mut(&mut self) -> &mut [mem::MaybeUninit<T>] {
+        self.split_at_spare_mut().1
+    }
+
+    /// Returns the vector's contents as a slice of `T`, along with the
+    /// remaining spare capacity of the vector as a slice of `MaybeUninit<T>`.
+    ///
+    /// The returned spare capacity slice can be used to fill the vector with
+    /// data (e.g. by reading from a file) before marking the data as
+    /// initialized using the [`set_len`] method.
+    ///
+    /// [`set_len`]: #method.set_len
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let mut v = bumpalo::vec![in &b; 1, 1, 2];
+    ///
+    /// // Reserve additional space big enough for 10 elements.
+    /// v.reserve(10);
+    ///
+    /// let (init, uninit) = v.split_at_spare_mut();
+    /// let sum = init.iter().copied().sum::<u32>();
+    ///
+    /// // Fill in the next 4 elements.
+    /// uninit[0].write(sum);
+    /// uninit[1].write(sum * 2);
+    /// uninit[2].write(sum * 3);
+    /// uninit[3].write(sum * 4);
+    ///
+    /// // Mark the 4 elements of the vector as being initialized.
+    /// unsafe {
+    ///     let len = v.len();
+    ///     v.set_len(len + 4);
+    /// }
+    ///
+    /// assert_eq!(&v, &[1, 1, 2, 4, 8, 12, 16]);
+    /// ```
+    #[inline]
+    pub fn split_at_spare_mut(&mut self) -> (&mut [T], &mut [mem::MaybeUninit<T>]) {
+        let len = self.len;
+        let cap = self.buf.cap();
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            let spare_ptr = ptr.add(len) as *mut mem::MaybeUninit<T>;
+            (
+                slice::from_raw_parts_mut(ptr, len),
+                slice::from_raw_parts_mut(spare_ptr, cap - len),
+            )
+        }
+    }
+
     /// Splits the collection into two at the given index.
     ///
     /// Returns a newly allocated `Self`. `self` contains elements `[0, at)`,
@@ -1524,6 +2085,90 @@ impl<'bump, T: 'bump + Clone> Vec<'bump, T> {
     pub fn extend_from_slice(&mut self, other: &[T]) {
         self.extend(other.iter().cloned())
     }
+
+    /// Copies elements from `src` range to the end of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let mut vec = bumpalo::vec![in &b; 0, 1, 2, 3, 4];
+    ///
+    /// vec.extend_from_within(2..);
+    /// assert_eq!(vec, [0, 1, 2, 3, 4, 2, 3, 4]);
+    ///
+    /// vec.extend_from_within(..2);
+    /// assert_eq!(vec, [0, 1, 2, 3, 4, 2, 3, 4, 0, 1]);
+    ///
+    /// vec.extend_from_within(4..8);
+    /// assert_eq!(vec, [0, 1, 2, 3, 4, 2, 3, 4, 0, 1, 4, 2, 3, 4]);
+    /// ```
+    pub fn extend_from_within<R>(&mut self, src: R)
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = range_to_bounds(src, self.len());
+        self.reserve(end - start);
+
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            // Keep the length up to date as we go, so that the clones made so
+            // far are dropped with the vector if a `clone` panics.
+            let mut local_len = SetLenOnDrop::new(&mut self.len);
+            for i in start..end {
+                let value = (*ptr.add(i)).clone();
+                ptr::write(ptr.add(local_len.current_len()), value);
+                local_len.increment_len(1);
+            }
+        }
+    }
+
+    /// Clones and appends all elements in a slice to the `Vec`, like
+    /// [`extend_from_slice`], but returns an error instead of panicking if the
+    /// capacity overflows or the arena fails to allocate.
+    ///
+    /// The space for all of `other` is reserved up front, so on error the
+    /// vector is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let mut vec = bumpalo::vec![in &b; 1];
+    /// vec.try_extend_from_slice(&[2, 3, 4]).unwrap();
+    /// assert_eq!(vec, [1, 2, 3, 4]);
+    /// ```
+    ///
+    /// [`extend_from_slice`]: #method.extend_from_slice
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), CollectionAllocErr> {
+        self.try_reserve(other.len())?;
+        for x in other {
+            unsafe {
+                let end = self.as_mut_ptr().add(self.len);
+                ptr::write(end, x.clone());
+                self.len += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[doc(hidden)]
+pub fn from_elem_in<'bump, T: Clone>(elem: T, n: usize, bump: &'bump Bump) -> Vec<'bump, T> {
+    let mut v = Vec::with_capacity_in(n, bump);
+    v.extend_with(n, ExtendElement(elem));
+    v
 }
 
 // This code generalises `extend_with_{element,default}`.
@@ -1597,6 +2242,11 @@ impl<'a> SetLenOnDrop<'a> {
         self.local_len += increment;
     }
 
+    #[inline]
+    fn current_len(&self) -> usize {
+        self.local_len
+    }
+
     #[inline]
     fn decrement_len(&mut self, decrement: usize) {
         self.local_len -= decrement;
@@ -1712,7 +2362,7 @@ impl<'bump, T: 'bump> ops::DerefMut for Vec<'bump, T> {
 
 impl<'bump, T: 'bump> IntoIterator for Vec<'bump, T> {
     type Item = T;
-    type IntoIter = IntoIter<T>;
+    type IntoIter = IntoIter<'bump, T>;
 
     /// Creates a consuming iterator, that is, one that moves each value out of
     /// the vector (from start to end). The vector cannot be used after calling
@@ -1732,7 +2382,7 @@ impl<'bump, T: 'bump> IntoIterator for Vec<'bump, T> {
     /// }
     /// ```
     #[inline]
-    fn into_iter(mut self) -> IntoIter<T> {
+    fn into_iter(mut self) -> IntoIter<'bump, T> {
         unsafe {
             let begin = self.as_mut_ptr();
             // assume(!begin.is_null());
@@ -1772,12 +2422,34 @@ impl<'a, 'bump, T> IntoIterator for &'a mut Vec<'bump, T> {
 impl<'bump, T: 'bump> Extend<T> for Vec<'bump, T> {
     #[inline]
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower_bound, _) = iter.size_hint();
+        self.reserve(lower_bound);
         for t in iter {
             self.push(t);
         }
     }
 }
 
+impl<'old, 'new, T: 'old + CloneIn<'new>> CloneIn<'new> for Vec<'old, T> {
+    type Cloned = Vec<'new, T::Cloned>;
+
+    fn clone_in(&self, bump: &'new Bump) -> Self::Cloned {
+        let mut v = Vec::with_capacity_in(self.len(), bump);
+        for x in self.iter() {
+            v.push(x.clone_in(bump));
+        }
+        v
+    }
+}
+
+impl<'bump, T: 'bump> FromIteratorIn<'bump, T> for Vec<'bump, T> {
+    #[inline]
+    fn from_iter_in<I: IntoIterator<Item = T>>(iter: I, bump: &'bump Bump) -> Vec<'bump, T> {
+        Vec::from_iter_in(iter, bump)
+    }
+}
+
 impl<'bump, T: 'bump> Vec<'bump, T> {
     /// Creates a splicing iterator that replaces the specified range in the vector
     /// with the given `replace_with` iterator and yields the removed items.
@@ -1966,19 +2638,19 @@ impl<'bump, T: 'bump> AsMut<[T]> for Vec<'bump, T> {
 ///
 /// [`Vec`]: struct.Vec.html
 /// [`IntoIterator`]: https://doc.rust-lang.org/nightly/std/iter/trait.IntoIterator.html
-pub struct IntoIter<T> {
-    phantom: PhantomData<T>,
+pub struct IntoIter<'bump, T> {
+    phantom: PhantomData<(&'bump [T], T)>,
     ptr: *const T,
     end: *const T,
 }
 
-impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+impl<'bump, T: fmt::Debug> fmt::Debug for IntoIter<'bump, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
     }
 }
 
-impl<'bump, T: 'bump> IntoIter<T> {
+impl<'bump, T: 'bump> IntoIter<'bump, T> {
     /// Returns the remaining items of this iterator as a slice.
     ///
     /// # Examples
@@ -2018,12 +2690,63 @@ impl<'bump, T: 'bump> IntoIter<T> {
     pub fn as_mut_slice(&mut self) -> &mut [T] {
         unsafe { slice::from_raw_parts_mut(self.ptr as *mut T, self.len()) }
     }
+
+    /// Converts the remaining items of this iterator into `&'bump [T]`,
+    /// keeping them allocated in the arena.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let vec = bumpalo::vec![in &b; 'a', 'b', 'c'];
+    /// let mut into_iter = vec.into_iter();
+    /// assert_eq!(into_iter.next(), Some('a'));
+    /// let rest = into_iter.into_bump_slice();
+    /// assert_eq!(rest, ['b', 'c']);
+    /// ```
+    pub fn into_bump_slice(self) -> &'bump [T] {
+        self.into_bump_slice_mut()
+    }
+
+    /// Converts the remaining items of this iterator into `&'bump mut [T]`,
+    /// keeping them allocated in the arena.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpalo::{Bump, collections::Vec};
+    ///
+    /// let b = Bump::new();
+    ///
+    /// let vec = bumpalo::vec![in &b; 3, 2, 1, 0];
+    /// let mut into_iter = vec.into_iter();
+    /// assert_eq!(into_iter.next_back(), Some(0));
+    /// let rest = into_iter.into_bump_slice_mut();
+    /// rest.sort();
+    /// assert_eq!(rest, [1, 2, 3]);
+    /// ```
+    pub fn into_bump_slice_mut(self) -> &'bump mut [T] {
+        unsafe {
+            let len = self.len();
+            // The pointers of zero-sized types are advanced a byte at a time,
+            // so they may not be aligned.
+            let ptr = if mem::size_of::<T>() == 0 {
+                NonNull::dangling().as_ptr()
+            } else {
+                self.ptr as *mut T
+            };
+            slice::from_raw_parts_mut(ptr, len)
+        }
+    }
 }
 
-unsafe impl<T: Send> Send for IntoIter<T> {}
-unsafe impl<T: Sync> Sync for IntoIter<T> {}
+unsafe impl<'bump, T: Send> Send for IntoIter<'bump, T> {}
+unsafe impl<'bump, T: Sync> Sync for IntoIter<'bump, T> {}
 
-impl<'bump, T: 'bump> Iterator for IntoIter<T> {
+impl<'bump, T: 'bump> Iterator for IntoIter<'bump, T> {
     type Item = T;
 
     #[inline]
@@ -2066,7 +2789,7 @@ impl<'bump, T: 'bump> Iterator for IntoIter<T> {
     }
 }
 
-impl<'bump, T: 'bump> DoubleEndedIterator for IntoIter<T> {
+impl<'bump, T: 'bump> DoubleEndedIterator for IntoIter<'bump, T> {
     #[inline]
     fn next_back(&mut self) -> Option<T> {
         unsafe {
@@ -2089,9 +2812,9 @@ impl<'bump, T: 'bump> DoubleEndedIterator for IntoIter<T> {
     }
 }
 
-impl<'bump, T: 'bump> ExactSizeIterator for IntoIter<T> {}
+impl<'bump, T: 'bump> ExactSizeIterator for IntoIter<'bump, T> {}
 
-impl<'bump, T: 'bump> FusedIterator for IntoIter<T> {}
+impl<'bump, T: 'bump> FusedIterator for IntoIter<'bump, T> {}
 
 /// A draining iterator for `Vec<'bump, T>`.
 ///
@@ -2339,3 +3062,82 @@ where
         }
     }
 }
+
+/// An iterator which uses a closure to determine if an element should be
+/// removed.
+///
+/// This `struct` is created by the [`extract_if`] method on [`Vec`].
+///
+/// [`extract_if`]: struct.Vec.html#method.extract_if
+/// [`Vec`]: struct.Vec.html
+#[derive(Debug)]
+pub struct ExtractIf<'a, 'bump: 'a, T: 'a + 'bump, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    vec: &'a mut Vec<'bump, T>,
+    /// The index of the item that will be inspected by the next call to `next`.
+    idx: usize,
+    /// Elements at and beyond this point will be retained.
+    end: usize,
+    /// The number of items that have been drained (removed) thus far.
+    del: usize,
+    /// The original length of `vec` prior to draining.
+    old_len: usize,
+    /// The filter test predicate.
+    pred: F,
+}
+
+impl<'a, 'bump, T, F> Iterator for ExtractIf<'a, 'bump, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        unsafe {
+            while self.idx < self.end {
+                let i = self.idx;
+                let v = slice::from_raw_parts_mut(self.vec.as_mut_ptr(), self.old_len);
+                let drained = (self.pred)(&mut v[i]);
+                // Update the index *after* the predicate is called. If the
+                // index is updated prior and the predicate panics, the element
+                // at this index would be leaked.
+                self.idx += 1;
+                if drained {
+                    self.del += 1;
+                    return Some(ptr::read(&v[i]));
+                } else if self.del > 0 {
+                    let del = self.del;
+                    let src: *const T = &v[i];
+                    let dst: *mut T = &mut v[i - del];
+                    ptr::copy_nonoverlapping(src, dst, 1);
+                }
+            }
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.end - self.idx))
+    }
+}
+
+impl<'a, 'bump, T, F> Drop for ExtractIf<'a, 'bump, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        unsafe {
+            if self.idx < self.old_len && self.del > 0 {
+                // Move the unvisited elements, and everything after the
+                // range, back over the hole left by the removed elements.
+                let ptr = self.vec.as_mut_ptr();
+                let src = ptr.add(self.idx);
+                let dst = src.sub(self.del);
+                ptr::copy(src, dst, self.old_len - self.idx);
+            }
+            self.vec.set_len(self.old_len - self.del);
+        }
+    }
+}
@@ -0,0 +1,63 @@
+/// A header value followed by a slice of values, laid out contiguously in a
+/// single allocation.
+///
+/// This is a dynamically sized type, so it is always used behind a reference.
+/// Allocate one with
+/// [`Bump::alloc_header_slice_copy`](./struct.Bump.html#method.alloc_header_slice_copy)
+/// or
+/// [`Bump::alloc_header_slice_fill_iter`](./struct.Bump.html#method.alloc_header_slice_fill_iter).
+///
+/// ## Example
+///
+/// ```
+/// use bumpalo::{Bump, HeaderSlice};
+///
+/// #[derive(Debug)]
+/// enum Op {
+///     Add,
+///     Mul,
+/// }
+///
+/// // A node in an expression tree, with its operator and children in one
+/// // allocation.
+/// type Node<'a> = HeaderSlice<Op, &'a Expr<'a>>;
+///
+/// #[derive(Debug)]
+/// enum Expr<'a> {
+///     Num(u32),
+///     Node(&'a Node<'a>),
+/// }
+///
+/// fn eval(e: &Expr) -> u32 {
+///     match e {
+///         Expr::Num(n) => *n,
+///         Expr::Node(node) => {
+///             let children = node.slice.iter().map(|c| eval(c));
+///             match node.header {
+///                 Op::Add => children.sum(),
+///                 Op::Mul => children.product(),
+///             }
+///         }
+///     }
+/// }
+///
+/// let bump = Bump::new();
+/// let two = bump.alloc(Expr::Num(2));
+/// let three = bump.alloc(Expr::Num(3));
+/// let four = bump.alloc(Expr::Num(4));
+///
+/// let sum = bump.alloc_header_slice_copy(Op::Add, &[&*two, &*three]);
+/// let sum = bump.alloc(Expr::Node(sum));
+/// let product = bump.alloc_header_slice_copy(Op::Mul, &[&*sum, &*four]);
+///
+/// assert_eq!(eval(&Expr::Node(product)), 20);
+/// ```
+#[derive(Debug)]
+#[repr(C)]
+pub struct HeaderSlice<H, T> {
+    /// The header value.
+    pub header: H,
+
+    /// The trailing slice of values.
+    pub slice: [T],
+}
@@ -90,6 +90,42 @@ Eventually [all `std` collection types will be parameterized by an
 allocator](https://github.com/rust-lang/rust/issues/42774) and we can remove
 this `collections` module and use the `std` versions.
 
+## Deriving `CloneIn`
+
+When the optional `"derive"` feature is enabled, `#[derive(CloneIn)]` is
+available for structs and enums whose first lifetime parameter is the lifetime
+of the arena they live in. The derived `clone_in` rebuilds the whole value in
+another arena, which is handy for moving a finished tree out of a short-lived
+arena.
+
+```rust
+# #[cfg(feature = "derive")]
+# fn main() {
+use bumpalo::{collections::Vec, Bump, CloneIn};
+
+#[derive(CloneIn)]
+struct Node<'bump> {
+    name: &'bump str,
+    children: Vec<'bump, Node<'bump>>,
+}
+
+fn keep<'new>(node: &Node<'_>, long_lived: &'new Bump) -> Node<'new> {
+    node.clone_in(long_lived)
+}
+
+let long_lived = Bump::new();
+let tree = {
+    let scratch = Bump::new();
+    let leaf = Node { name: "leaf", children: Vec::new_in(&scratch) };
+    let root = Node { name: "root", children: bumpalo::vec![in &scratch; leaf] };
+    keep(&root, &long_lived)
+};
+assert_eq!(tree.children[0].name, "leaf");
+# }
+# #[cfg(not(feature = "derive"))]
+# fn main() {}
+```
+
 ## `#![no_std]` Support
 
 Requires the `alloc` nightly feature. Disable the on-by-default `"std"` feature:
@@ -111,32 +147,131 @@ default-features = false
 #[cfg(feature = "std")]
 extern crate core;
 
+// The exported macros reach `core` through here, so that they work whether or
+// not the calling crate has `core` or `std` in scope.
+#[doc(hidden)]
+pub mod __private {
+    pub use core::fmt::Write;
+    pub use core::format_args;
+}
+
+/// Append formatted text to a `bumpalo::collections::String`, a
+/// [`TailBuffer`](struct.TailBuffer.html), or any other
+/// [`fmt::Write`](https://doc.rust-lang.org/nightly/std/fmt/trait.Write.html)
+/// implementation.
+///
+/// Unlike `write!`, this doesn't require the `Write` trait to be in scope, and
+/// doesn't return a `Result`: it panics if the destination returns an error,
+/// which bump-allocated strings never do.
+///
+/// ## Example
+///
+/// ```
+/// let bump = bumpalo::Bump::new();
+///
+/// let mut buf = bump.tail_buffer();
+/// for i in 0..3 {
+///     bumpalo::write_in!(buf, "{},", i);
+/// }
+/// assert_eq!(buf.into_bump_str().unwrap(), "0,1,2,");
+/// ```
+#[macro_export]
+macro_rules! write_in {
+    ( $dst:expr, $($arg:tt)* ) => {
+        $crate::__private::Write::write_fmt(
+            &mut $dst,
+            $crate::__private::format_args!($($arg)*),
+        )
+        .expect("a formatting trait implementation returned an error")
+    }
+}
+
+/// Format text directly into a `&'bump str`, like `format!` does into a
+/// `String`.
+///
+/// The text is formatted with
+/// [`Bump::alloc_fmt`](struct.Bump.html#method.alloc_fmt), so it is allocated
+/// with exactly its own size and no intermediate string has to be grown.
+///
+/// ## Example
+///
+/// ```
+/// use bumpalo::Bump;
+///
+/// let b = Bump::new();
+///
+/// let x = 42;
+/// let s: &str = bumpalo::format_str!(in &b, "x = {x}, x + 1 = {}", x + 1);
+/// assert_eq!(s, "x = 42, x + 1 = 43");
+/// ```
+#[macro_export]
+macro_rules! format_str {
+    ( in $bump:expr, $($arg:tt)* ) => {{
+        let s: &str = ($bump).alloc_fmt($crate::__private::format_args!($($arg)*));
+        s
+    }}
+}
+
 #[cfg(feature = "collections")]
 pub mod collections;
 
 mod alloc;
 
+#[cfg(feature = "std")]
+pub mod scratch;
+
+mod retired;
+pub use crate::retired::RetiredChunks;
+
+mod header_slice;
+pub use crate::header_slice::HeaderSlice;
+
+mod alloc_many;
+pub use crate::alloc_many::AllocMany;
+
+mod clone_in;
+pub use crate::clone_in::CloneIn;
+#[cfg(feature = "derive")]
+pub use bumpalo_derive::CloneIn;
+
+mod tail_buffer;
+pub use crate::tail_buffer::TailBuffer;
+
+mod chunk_index;
+pub use crate::chunk_index::ChunkIndex;
+
+mod chunks;
+pub use crate::chunks::{AllocatedChunk, AllocatedChunks, RawAllocatedChunk, RawAllocatedChunks};
+
 #[cfg(feature = "std")]
 mod imports {
     pub use std::alloc::{alloc, dealloc, Layout};
     pub use std::cell::{Cell, UnsafeCell};
     pub use std::cmp;
     pub use std::fmt;
-    pub use std::mem;
+    pub use std::marker::PhantomData;
+    pub use std::mem::{self, MaybeUninit};
+    pub use std::pin::Pin;
     pub use std::ptr::{self, NonNull};
     pub use std::slice;
+    pub use std::str;
+    pub use std::vec::Vec;
 }
 
 #[cfg(not(feature = "std"))]
 mod imports {
     extern crate alloc;
     pub use self::alloc::alloc::{alloc, dealloc, Layout};
+    pub use self::alloc::vec::Vec;
     pub use core::cell::{Cell, UnsafeCell};
     pub use core::cmp;
     pub use core::fmt;
-    pub use core::mem;
+    pub use core::marker::PhantomData;
+    pub use core::mem::{self, MaybeUninit};
+    pub use core::pin::Pin;
     pub use core::ptr::{self, NonNull};
     pub use core::slice;
+    pub use core::str;
 }
 
 use crate::imports::*;
@@ -189,16 +324,46 @@ use crate::imports::*;
 /// let mut s = bump.alloc("bumpalo");
 /// *s = "the bump allocator; and also is a buffalo";
 /// ```
+///
+/// ## Minimum alignment
+///
+/// The `MIN_ALIGN` parameter is a minimum alignment that the bump finger is
+/// always kept at. Allocations whose alignment is at most `MIN_ALIGN` don't
+/// need to round the finger up; instead, their sizes are rounded up to a
+/// multiple of `MIN_ALIGN`. If most values allocated in an arena share the same
+/// alignment, using it as `MIN_ALIGN` makes allocation a little cheaper.
+///
+/// `MIN_ALIGN` must be a power of two. It defaults to `1`, which never rounds
+/// sizes up. The collections in `bumpalo::collections` use arenas with the
+/// default minimum alignment.
+///
+/// ```
+/// use bumpalo::Bump;
+///
+/// let bump = Bump::<8>::with_min_align();
+///
+/// let x = bump.alloc(1_u8) as *mut u8 as usize;
+/// let y = bump.alloc(2_u64) as *mut u64 as usize;
+/// assert_eq!(x % 8, 0);
+/// assert_eq!(y % 8, 0);
+/// ```
 #[derive(Debug)]
-pub struct Bump {
-    // The current chunk we are bump allocating within.
-    current_chunk_footer: Cell<NonNull<ChunkFooter>>,
+pub struct Bump<const MIN_ALIGN: usize = 1> {
+    // The current chunk we are bump allocating within, or `None` if we haven't
+    // allocated any chunks yet.
+    current_chunk_footer: Cell<Option<NonNull<ChunkFooter>>>,
 
     // The first chunk we were ever given, which is the head of the intrusive
     // linked list of all chunks this arena has been bump allocating within.
-    all_chunk_footers: Cell<NonNull<ChunkFooter>>,
+    // This is `None` exactly when `current_chunk_footer` is `None`.
+    all_chunk_footers: Cell<Option<NonNull<ChunkFooter>>>,
 }
 
+// A `Bump` exclusively owns all of its chunks, and nothing borrowed from it can
+// outlive a move of the `Bump` itself, so it is safe to send an arena to
+// another thread.
+unsafe impl<const MIN_ALIGN: usize> Send for Bump<MIN_ALIGN> {}
+
 #[repr(C)]
 #[derive(Debug)]
 struct ChunkFooter {
@@ -216,24 +381,51 @@ struct ChunkFooter {
     ptr: Cell<NonNull<u8>>,
 }
 
-impl Drop for Bump {
+impl<const MIN_ALIGN: usize> Drop for Bump<MIN_ALIGN> {
     fn drop(&mut self) {
         unsafe {
-            let mut footer = Some(self.all_chunk_footers.get());
-            while let Some(f) = footer {
-                footer = f.as_ref().next.get();
-                dealloc(f.as_ref().data.as_ptr(), Bump::default_chunk_layout());
-            }
+            dealloc_chunk_list(self.all_chunk_footers.get());
         }
     }
 }
 
+// Return every chunk in the list starting at `footer` to the global allocator.
+#[inline]
+unsafe fn dealloc_chunk_list(mut footer: Option<NonNull<ChunkFooter>>) {
+    while let Some(f) = footer {
+        footer = f.as_ref().next.get();
+        dealloc(f.as_ref().data.as_ptr(), f.as_ref().layout.clone());
+    }
+}
+
+// The addresses of the part of a chunk that has been allocated so far.
+#[inline]
+fn chunk_allocated_range(footer: &ChunkFooter) -> core::ops::Range<usize> {
+    footer.data.as_ptr() as usize..footer.ptr.get().as_ptr() as usize
+}
+
+// Find the last chunk in the list starting at `head`.
+unsafe fn chunk_list_tail(head: NonNull<ChunkFooter>) -> NonNull<ChunkFooter> {
+    let mut tail = head;
+    while let Some(next) = tail.as_ref().next.get() {
+        tail = next;
+    }
+    tail
+}
+
 #[inline]
 pub(crate) fn round_up_to(n: usize, divisor: usize) -> usize {
     debug_assert!(divisor.is_power_of_two());
     (n + divisor - 1) & !(divisor - 1)
 }
 
+// Like `round_up_to`, but returns `None` on overflow.
+#[inline]
+fn checked_round_up_to(n: usize, divisor: usize) -> Option<usize> {
+    debug_assert!(divisor.is_power_of_two());
+    Some(n.checked_add(divisor - 1)? & !(divisor - 1))
+}
+
 // Maximum typical overhead per allocation imposed by allocators.
 const MALLOC_OVERHEAD: usize = 16;
 
@@ -243,6 +435,11 @@ const MALLOC_OVERHEAD: usize = 16;
 const DEFAULT_CHUNK_SIZE_WITH_FOOTER: usize = (1 << 9) - MALLOC_OVERHEAD;
 const DEFAULT_CHUNK_ALIGN: usize = mem::align_of::<ChunkFooter>();
 
+// Allocations larger than this that don't fit in the current chunk get a
+// dedicated chunk of their own, rather than a new current chunk that would
+// inflate the size of every chunk allocated after it.
+const LARGE_OBJECT_THRESHOLD: usize = 1 << 16;
+
 /// Wrapper around `Layout::from_size_align` that adds debug assertions.
 #[inline]
 unsafe fn layout_from_size_align(size: usize, align: usize) -> Layout {
@@ -254,58 +451,146 @@ unsafe fn layout_from_size_align(size: usize, align: usize) -> Layout {
 }
 
 impl Bump {
-    fn default_chunk_layout() -> Layout {
-        unsafe { layout_from_size_align(DEFAULT_CHUNK_SIZE_WITH_FOOTER, DEFAULT_CHUNK_ALIGN) }
-    }
-
     /// Construct a new arena to bump allocate into.
     ///
+    /// The arena doesn't allocate any memory until the first time something
+    /// is allocated in it. This is a `const fn`, so arenas can be created in
+    /// `static`s.
+    ///
     /// ## Example
     ///
     /// ```
     /// let bump = bumpalo::Bump::new();
     /// # let _ = bump;
     /// ```
-    pub fn new() -> Bump {
-        let chunk_footer = Self::new_chunk(None);
+    ///
+    /// A lock-guarded arena in a `static`:
+    ///
+    /// ```
+    /// use bumpalo::Bump;
+    /// use std::sync::Mutex;
+    ///
+    /// static ARENA: Mutex<Bump> = Mutex::new(Bump::new());
+    ///
+    /// let mut arena = ARENA.lock().unwrap();
+    /// let x = arena.alloc(42);
+    /// assert_eq!(*x, 42);
+    /// arena.reset();
+    /// ```
+    pub const fn new() -> Bump {
+        Bump::with_min_align()
+    }
+}
+
+impl<const MIN_ALIGN: usize> Bump<MIN_ALIGN> {
+    // Evaluating this fails to compile unless `MIN_ALIGN` is a power of two.
+    const MIN_ALIGN_IS_POWER_OF_TWO: () = assert!(
+        MIN_ALIGN.is_power_of_two(),
+        "MIN_ALIGN must be a power of two"
+    );
+
+    // The alignment of every chunk, so that the bump finger starts out aligned
+    // to both our footer and `MIN_ALIGN`.
+    const CHUNK_ALIGN: usize = if MIN_ALIGN > DEFAULT_CHUNK_ALIGN {
+        MIN_ALIGN
+    } else {
+        DEFAULT_CHUNK_ALIGN
+    };
+
+    /// Construct a new arena to bump allocate into, whose bump finger is
+    /// always aligned to at least `MIN_ALIGN`.
+    ///
+    /// See [the `Bump` type's top-level documentation](./struct.Bump.html)
+    /// for details. Like [`new`](#method.new), this doesn't allocate any
+    /// memory.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::<16>::with_min_align();
+    /// let x = bump.alloc(42_u32);
+    /// assert_eq!(x as *mut u32 as usize % 16, 0);
+    /// ```
+    ///
+    /// A `MIN_ALIGN` that isn't a power of two is rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// let bump = bumpalo::Bump::<3>::with_min_align();
+    /// # let _ = bump;
+    /// ```
+    pub const fn with_min_align() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::MIN_ALIGN_IS_POWER_OF_TWO;
         Bump {
-            current_chunk_footer: Cell::new(chunk_footer),
-            all_chunk_footers: Cell::new(chunk_footer),
+            current_chunk_footer: Cell::new(None),
+            all_chunk_footers: Cell::new(None),
         }
     }
 
-    /// Allocate a new chunk and return its initialized footer.
+    // Round `size` up to a multiple of `MIN_ALIGN`, so that allocating it keeps
+    // the bump finger aligned. Returns `None` on overflow.
+    #[inline(always)]
+    fn round_size_to_min_align(size: usize) -> Option<usize> {
+        size.checked_add(MIN_ALIGN - 1)
+            .map(|size| size & !(MIN_ALIGN - 1))
+    }
+
+    /// Compute the layout of a new chunk.
     ///
-    /// If given, `layouts` is a tuple of the current chunk layout and the
-    /// layout of the allocation request that triggered us to fall back to
-    /// allocating a new chunk of memory.
-    fn new_chunk(layouts: Option<(Layout, Layout)>) -> NonNull<ChunkFooter> {
-        unsafe {
-            let layout: Layout =
-                layouts.map_or_else(Bump::default_chunk_layout, |(old, requested)| {
-                    let old_doubled = old.size().checked_mul(2).unwrap();
-                    debug_assert_eq!(
-                        old_doubled,
-                        round_up_to(old_doubled, mem::align_of::<ChunkFooter>()),
-                        "The old size was already a multiple of our chunk footer alignment, so no \
-                         need to round it up again."
-                    );
-
-                    // Round the size up to a multiple of our footer's alignment so that
-                    // we can be sure that our footer is properly aligned.
-                    let requested_size =
-                        round_up_to(requested.size(), mem::align_of::<ChunkFooter>());
-
-                    let size = cmp::max(old_doubled, requested_size);
-                    let align = cmp::max(old.align(), requested.align());
-                    layout_from_size_align(size, align)
-                });
+    /// `old` is the layout of the current chunk, if there is one, and
+    /// `requested` is the layout of the allocation request that triggered us
+    /// to fall back to allocating a new chunk of memory. The new chunk is at
+    /// least twice as large as the old one, and always large enough to hold
+    /// both `requested` and the chunk's footer.
+    ///
+    /// Returns `None` if the chunk's size overflows.
+    fn new_chunk_layout(old: Option<Layout>, requested: Layout) -> Option<Layout> {
+        let (old_doubled, old_align) = match old {
+            Some(old) => {
+                let old_doubled = old.size().checked_mul(2)?;
+                debug_assert_eq!(
+                    old_doubled,
+                    round_up_to(old_doubled, mem::align_of::<ChunkFooter>()),
+                    "The old size was already a multiple of our chunk footer alignment, so no \
+                     need to round it up again."
+                );
+                (old_doubled, old.align())
+            }
+            // This is the first chunk for this arena.
+            None => (DEFAULT_CHUNK_SIZE_WITH_FOOTER, Self::CHUNK_ALIGN),
+        };
+
+        let size = cmp::max(old_doubled, Self::chunk_size_for(requested)?);
+        let align = cmp::max(old_align, requested.align());
+        Layout::from_size_align(size, align).ok()
+    }
+
+    /// Compute the layout of a chunk that is exactly big enough to hold an
+    /// allocation of the `requested` layout.
+    ///
+    /// Returns `None` if the chunk's size overflows.
+    fn large_chunk_layout(requested: Layout) -> Option<Layout> {
+        let size = Self::chunk_size_for(requested)?;
+        let align = cmp::max(Self::CHUNK_ALIGN, requested.align());
+        Layout::from_size_align(size, align).ok()
+    }
 
+    // The size of the smallest chunk that can hold an allocation of the
+    // `requested` layout. The requested size is rounded up to a multiple of our
+    // footer's alignment so that we can be sure that our footer is properly
+    // aligned, and the footer itself goes after it.
+    fn chunk_size_for(requested: Layout) -> Option<usize> {
+        checked_round_up_to(requested.size(), mem::align_of::<ChunkFooter>())?
+            .checked_add(mem::size_of::<ChunkFooter>())
+    }
+
+    /// Allocate a chunk with the given layout from the global allocator, and
+    /// return its initialized footer, or `None` if the global allocator fails.
+    fn alloc_chunk(layout: Layout) -> Option<NonNull<ChunkFooter>> {
+        unsafe {
             let size = layout.size();
 
-            let data = alloc(layout);
-            assert!(!data.is_null());
-            let data = NonNull::new_unchecked(data);
+            let data = NonNull::new(alloc(layout))?;
 
             let next = Cell::new(None);
             let ptr = Cell::new(data);
@@ -320,7 +605,7 @@ impl Bump {
                     ptr,
                 },
             );
-            NonNull::new_unchecked(footer_ptr)
+            Some(NonNull::new_unchecked(footer_ptr))
         }
     }
 
@@ -357,27 +642,63 @@ impl Bump {
     /// }
     ///```
     pub fn reset(&mut self) {
+        drop(self.reset_retire());
+    }
+
+    /// Reset this bump allocator like [`reset`](#method.reset), but hand back
+    /// the chunks that would have been returned to the global allocator
+    /// instead of deallocating them.
+    ///
+    /// The returned [`RetiredChunks`](./struct.RetiredChunks.html) frees the
+    /// chunks whenever it is dropped, which can happen later or on another
+    /// thread. See also [`reset_deferred`](#method.reset_deferred).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut bump = bumpalo::Bump::new();
+    ///
+    /// for i in 0..10_000 {
+    ///     bump.alloc(i);
+    /// }
+    ///
+    /// let retired = bump.reset_retire();
+    /// assert!(!retired.is_empty());
+    ///
+    /// // Free the old chunks on some other thread.
+    /// std::thread::spawn(move || drop(retired)).join().unwrap();
+    /// ```
+    pub fn reset_retire(&mut self) -> RetiredChunks {
         // Takes `&mut self` so `self` must be unique and there can't be any
         // borrows active that would get invalidated by resetting.
         unsafe {
-            let mut footer = Some(self.all_chunk_footers.get());
+            let current = match self.current_chunk_footer.get() {
+                Some(current) => current,
+                // Without a current chunk, the only chunks we can have are
+                // large-object chunks, and all of them get retired.
+                None => return RetiredChunks::from_list(self.all_chunk_footers.take()),
+            };
+
+            let mut footer = self.all_chunk_footers.get();
+            let mut retired = None;
 
             // Reset the pointer in each of our chunks.
             while let Some(f) = footer {
                 footer = f.as_ref().next.get();
 
-                if f == self.current_chunk_footer.get() {
+                if f == current {
                     // If this is the current chunk, then reset the bump finger
                     // to the start of the chunk.
                     f.as_ref()
                         .ptr
                         .set(NonNull::new_unchecked(f.as_ref().data.as_ptr() as *mut u8));
                     f.as_ref().next.set(None);
-                    self.all_chunk_footers.set(f);
+                    self.all_chunk_footers.set(Some(f));
                 } else {
-                    // If this is not the current chunk, return it to the global
-                    // allocator.
-                    dealloc(f.as_ref().data.as_ptr(), f.as_ref().layout.clone());
+                    // If this is not the current chunk, move it onto the list
+                    // of retired chunks.
+                    f.as_ref().next.set(retired);
+                    retired = Some(f);
                 }
             }
 
@@ -387,22 +708,195 @@ impl Bump {
                 "The current chunk should be the list head of all of our chunks"
             );
             debug_assert!(
-                self.current_chunk_footer
-                    .get()
-                    .as_ref()
-                    .next
-                    .get()
-                    .is_none(),
+                current.as_ref().next.get().is_none(),
                 "We should only have a single chunk"
             );
             debug_assert_eq!(
-                self.current_chunk_footer.get().as_ref().ptr.get(),
-                self.current_chunk_footer.get().as_ref().data,
+                current.as_ref().ptr.get(),
+                current.as_ref().data,
                 "Our chunk's bump finger should be reset to the start of its allocation"
             );
+
+            RetiredChunks::from_list(retired)
+        }
+    }
+
+    /// Reset this bump allocator like [`reset`](#method.reset), but return the
+    /// excess chunks to the global allocator on a background thread instead of
+    /// on this one.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut bump = bumpalo::Bump::new();
+    ///
+    /// for i in 0..10_000 {
+    ///     bump.alloc(i);
+    /// }
+    ///
+    /// bump.reset_deferred();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn reset_deferred(&mut self) {
+        self.reset_retire().dealloc_deferred();
+    }
+
+    /// Consume this bump allocator and hand back all of its chunks, without
+    /// deallocating them.
+    ///
+    /// Dropping a large arena deallocates each of its chunks one at a time.
+    /// The returned [`RetiredChunks`](./struct.RetiredChunks.html) lets that
+    /// work happen later, for example by pushing it onto a queue that is
+    /// drained when the program is otherwise idle. See also
+    /// [`drop_deferred`](#method.drop_deferred).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// bump.alloc("hello");
+    ///
+    /// let mut queue = vec![bump.retire()];
+    ///
+    /// // ...later...
+    /// queue.clear();
+    /// ```
+    pub fn retire(self) -> RetiredChunks {
+        let head = self.all_chunk_footers.get();
+        mem::forget(self);
+        unsafe { RetiredChunks::from_list(head) }
+    }
+
+    /// Drop this bump allocator, returning its chunks to the global allocator
+    /// on a background thread instead of on this one.
+    ///
+    /// The background thread is spawned the first time it is needed, and is
+    /// shared by all arenas. If it can't be spawned, the chunks are
+    /// deallocated on this thread instead.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    ///
+    /// for i in 0..10_000 {
+    ///     bump.alloc(i);
+    /// }
+    ///
+    /// bump.drop_deferred();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn drop_deferred(self) {
+        self.retire().dealloc_deferred();
+    }
+
+    /// Take ownership of all of `other`'s chunks, so that they are freed
+    /// together with this arena's chunks.
+    ///
+    /// No memory is copied: `other`'s chunk list is spliced into this arena's
+    /// chunk list. The absorbed chunks are not allocated into again, and they
+    /// are returned to the global allocator the next time this arena is
+    /// [`reset`](#method.reset) or dropped. The exception is when this arena
+    /// has not allocated any chunks of its own yet: then it adopts `other`'s
+    /// current chunk and keeps bump allocating in it.
+    ///
+    /// Because `other` is moved into this method, there can't be any borrows
+    /// of it left. To keep using values that were allocated in `other`, use
+    /// [`absorb_with`](#method.absorb_with) to re-tie a reference into `other`
+    /// to this arena's lifetime.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bumpalo::Bump;
+    /// use std::thread;
+    ///
+    /// let bump = Bump::new();
+    ///
+    /// // Do some allocation in a worker thread's own arena.
+    /// let worker = thread::spawn(|| {
+    ///     let worker_bump = Bump::new();
+    ///     for i in 0..100 {
+    ///         worker_bump.alloc(i);
+    ///     }
+    ///     worker_bump
+    /// });
+    ///
+    /// // And free it together with `bump` later on.
+    /// bump.absorb(worker.join().unwrap());
+    /// ```
+    pub fn absorb(&self, other: Bump<MIN_ALIGN>) {
+        unsafe {
+            // The current chunk is always the tail of the chunk list, so
+            // splicing `other`'s whole list in front of our head keeps our
+            // current chunk as the tail of the combined list.
+            if let Some(other_head) = other.all_chunk_footers.get() {
+                match (
+                    self.current_chunk_footer.get(),
+                    other.current_chunk_footer.get(),
+                ) {
+                    (None, Some(other_current)) => {
+                        // We don't have a current chunk of our own yet, so keep
+                        // bump allocating in `other`'s current chunk. Our
+                        // large-object chunks go in front of `other`'s list to
+                        // keep that chunk as the tail.
+                        match self.all_chunk_footers.get() {
+                            Some(head) => chunk_list_tail(head).as_ref().next.set(Some(other_head)),
+                            None => self.all_chunk_footers.set(Some(other_head)),
+                        }
+                        self.current_chunk_footer.set(Some(other_current));
+                    }
+                    (_, other_current) => {
+                        let other_tail =
+                            other_current.unwrap_or_else(|| chunk_list_tail(other_head));
+                        debug_assert!(
+                            other_tail.as_ref().next.get().is_none(),
+                            "The current chunk should be the tail of the chunk list"
+                        );
+                        other_tail.as_ref().next.set(self.all_chunk_footers.get());
+                        self.all_chunk_footers.set(Some(other_head));
+                    }
+                }
+            }
+
+            // The chunks are ours now; don't let `other` free them.
+            mem::forget(other);
         }
     }
 
+    /// Call `f` with `other`, then [`absorb`](#method.absorb) `other` into
+    /// this arena and return `f`'s result with this arena's lifetime.
+    ///
+    /// `f` is given a reference to `other` and returns a reference to a value
+    /// it allocated there. Since that value now lives in one of this arena's
+    /// chunks, it stays valid for as long as this arena is borrowed.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bumpalo::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let other = Bump::new();
+    ///
+    /// let results = bump.absorb_with(other, |other| {
+    ///     other.alloc([1, 2, 3])
+    /// });
+    ///
+    /// assert_eq!(*results, [1, 2, 3]);
+    /// ```
+    pub fn absorb_with<T, F>(&self, other: Bump<MIN_ALIGN>, f: F) -> &mut T
+    where
+        F: FnOnce(&Bump<MIN_ALIGN>) -> &mut T,
+    {
+        let result: *mut T = f(&other);
+        self.absorb(other);
+        // `f` can't have hidden any borrow of `other` inside `T`, because `T`
+        // is chosen independently of the lifetime of the `&Bump` it was given.
+        // And the chunk that `result` points into is owned by `self` now.
+        unsafe { &mut *result }
+    }
+
     /// Allocate an object in this `Bump` and return an exclusive reference to
     /// it.
     ///
@@ -429,67 +923,676 @@ impl Bump {
         }
     }
 
-    /// Allocate space for an object with the given `Layout`.
+    /// Allocate a tuple of objects in this `Bump` and return a tuple of
+    /// exclusive references to each of them.
     ///
-    /// The returned pointer points at uninitialized memory, and should be
-    /// initialized with
-    /// [`std::ptr::write`](https://doc.rust-lang.org/stable/std/ptr/fn.write.html).
+    /// All of the objects are placed in a single allocation, so this only
+    /// checks for room in the current chunk once. Their relative placement
+    /// respects each object's alignment, the same as it would in the tuple.
     ///
     /// ## Panics
     ///
-    /// Panics if reserving space for `T` would cause an overflow.
+    /// Panics if reserving space for the objects would cause an overflow.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// struct Frame {
+    ///     pc: usize,
+    /// }
+    ///
+    /// let bump = bumpalo::Bump::new();
+    /// let (frame, locals, stack) = bump.alloc_many((Frame { pc: 0 }, [0_u64; 4], [0_u8; 16]));
+    ///
+    /// frame.pc += 1;
+    /// locals[0] = 42;
+    /// stack[0] = 7;
+    /// assert_eq!((frame.pc, locals[0], stack[0]), (1, 42, 7));
+    /// ```
     #[inline(always)]
-    pub fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
-        unsafe {
-            let footer = self.current_chunk_footer.get();
-            let footer = footer.as_ref();
-            let ptr = footer.ptr.get().as_ptr() as usize;
-            let ptr = round_up_to(ptr, layout.align());
-            let end = footer as *const _ as usize;
-            debug_assert!(ptr <= end);
-
-            let new_ptr = match ptr.checked_add(layout.size()) {
-                Some(p) => p,
-                None => self.overflow(),
-            };
-
-            if new_ptr <= end {
-                let p = ptr as *mut u8;
-                debug_assert!(new_ptr <= footer as *const _ as usize);
-                footer.ptr.set(NonNull::new_unchecked(new_ptr as *mut u8));
-                return NonNull::new_unchecked(p);
-            }
-        }
-
-        self.alloc_layout_slow(layout)
+    pub fn alloc_many<'a, V>(&'a self, vals: V) -> V::Refs
+    where
+        V: AllocMany<'a> + 'a,
+    {
+        V::split_mut(self.alloc(vals))
     }
 
-    #[inline(never)]
-    #[cold]
-    fn overflow(&self) -> ! {
-        panic!("allocation too large, caused overflow")
+    /// Allocate an object in this `Bump` and return a pinned exclusive
+    /// reference to it.
+    ///
+    /// Values in an arena never move, so they can be pinned in place, for
+    /// example to poll a future or to link the value into an intrusive list.
+    ///
+    /// ## Pinning and `Drop`
+    ///
+    /// `Pin` guarantees that a pinned value's memory is not reused until the
+    /// value has been dropped, but `Bump` never drops the values allocated in
+    /// it. To uphold the guarantee anyway, `T` must not need to be dropped at
+    /// all: this is checked at compile time with
+    /// [`std::mem::needs_drop`](https://doc.rust-lang.org/stable/std/mem/fn.needs_drop.html).
+    /// Dropping such a value runs no code, so reusing its memory after a
+    /// [`reset`](#method.reset) is indistinguishable from dropping it first.
+    ///
+    /// Note that the futures produced by `async` blocks and functions always
+    /// have drop glue, so they can't be pinned in a `Bump`. Hand-written
+    /// futures that don't hold any values needing `Drop` can be.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for `T` would cause an overflow.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::future::{self, Future};
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// let bump = bumpalo::Bump::new();
+    /// let mut fut = bump.alloc_pinned(future::ready(42));
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(42));
+    /// ```
+    ///
+    /// Types that need to be dropped are rejected:
+    ///
+    /// ```compile_fail
+    /// let bump = bumpalo::Bump::new();
+    /// let s = bump.alloc_pinned(String::from("needs drop"));
+    /// # let _ = s;
+    /// ```
+    #[inline(always)]
+    pub fn alloc_pinned<T>(&self, val: T) -> Pin<&mut T> {
+        self.alloc_pinned_with(|| val)
     }
 
-    // Slow path allocation for when we need to allocate a new chunk from the
-    // parent bump set because there isn't enough room in our current chunk.
-    #[inline(never)]
-    fn alloc_layout_slow(&self, layout: Layout) -> NonNull<u8> {
-        unsafe {
-            let size = layout.size();
+    /// Allocate the object returned by `f` in this `Bump` and return a pinned
+    /// exclusive reference to it.
+    ///
+    /// `T` must not need to be dropped; see
+    /// [`alloc_pinned`](#method.alloc_pinned) for why.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for `T` would cause an overflow.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::marker::PhantomPinned;
+    ///
+    /// struct Node {
+    ///     value: u32,
+    ///     _pinned: PhantomPinned,
+    /// }
+    ///
+    /// let bump = bumpalo::Bump::new();
+    /// let node = bump.alloc_pinned_with(|| Node { value: 7, _pinned: PhantomPinned });
+    /// assert_eq!(node.value, 7);
+    /// ```
+    #[inline(always)]
+    pub fn alloc_pinned_with<F, T>(&self, f: F) -> Pin<&mut T>
+    where
+        F: FnOnce() -> T,
+    {
+        #[allow(clippy::let_unit_value)]
+        let () = NoDropGlue::<T>::ASSERT;
+
+        let p = self.alloc_uninit::<T>();
+        let val = p.write(f());
+        // Safe because the value lives at this address until the arena is
+        // reset or dropped, and it has no drop glue that could observe its
+        // memory being reused after that.
+        unsafe { Pin::new_unchecked(val) }
+    }
 
-            // Get a new chunk from the global allocator.
-            let current_layout = self.current_chunk_footer.get().as_ref().layout.clone();
-            let footer = Bump::new_chunk(Some((current_layout, layout)));
+    /// Allocate space for an object of type `T` in this `Bump`, without
+    /// initializing it, and return an exclusive reference to it.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for `T` would cause an overflow.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let x = bump.alloc_uninit::<u64>();
+    /// let x: &mut u64 = x.write(42);
+    /// assert_eq!(*x, 42);
+    /// ```
+    #[inline(always)]
+    pub fn alloc_uninit<T>(&self) -> &mut MaybeUninit<T> {
+        let layout = Layout::new::<T>();
+
+        unsafe {
+            let p = self.alloc_layout(layout);
+            &mut *(p.as_ptr() as *mut MaybeUninit<T>)
+        }
+    }
+
+    /// Allocate space for a slice of `len` objects of type `T` in this `Bump`,
+    /// without initializing them, and return an exclusive reference to the
+    /// slice.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for the slice would cause an overflow.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let bump = bumpalo::Bump::new();
+    /// let buf: &mut [MaybeUninit<u8>] = bump.alloc_uninit_slice(1024);
+    /// for (i, b) in buf.iter_mut().enumerate() {
+    ///     b.write(i as u8);
+    /// }
+    /// assert_eq!(buf.len(), 1024);
+    /// ```
+    #[inline(always)]
+    pub fn alloc_uninit_slice<T>(&self, len: usize) -> &mut [MaybeUninit<T>] {
+        let layout = match Layout::array::<T>(len) {
+            Ok(layout) => layout,
+            Err(_) => self.overflow(),
+        };
+
+        unsafe {
+            let p = self.alloc_layout(layout);
+            slice::from_raw_parts_mut(p.as_ptr() as *mut MaybeUninit<T>, len)
+        }
+    }
+
+    /// Allocate an object of type `T` whose bytes are all zero in this `Bump`,
+    /// and return an exclusive reference to it.
+    ///
+    /// Chunks are not zeroed when they are allocated or reset, so the memory
+    /// is always explicitly cleared.
+    ///
+    /// ## Safety
+    ///
+    /// The all-zero byte pattern must be a valid value of type `T`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for `T` would cause an overflow.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let x = unsafe { bump.alloc_zeroed::<[u32; 16]>() };
+    /// assert_eq!(*x, [0; 16]);
+    /// ```
+    #[inline(always)]
+    pub unsafe fn alloc_zeroed<T>(&self) -> &mut T {
+        let p = self.alloc_uninit::<T>();
+        ptr::write_bytes(p.as_mut_ptr(), 0, 1);
+        p.assume_init_mut()
+    }
+
+    /// Allocate a slice of `len` objects of type `T` whose bytes are all zero
+    /// in this `Bump`, and return an exclusive reference to the slice.
+    ///
+    /// Chunks are not zeroed when they are allocated or reset, so the memory
+    /// is always explicitly cleared.
+    ///
+    /// ## Safety
+    ///
+    /// The all-zero byte pattern must be a valid value of type `T`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for the slice would cause an overflow.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let xs = unsafe { bump.alloc_slice_zeroed::<f64>(100) };
+    /// assert!(xs.iter().all(|&x| x == 0.0));
+    /// ```
+    #[inline(always)]
+    pub unsafe fn alloc_slice_zeroed<T>(&self, len: usize) -> &mut [T] {
+        let p = self.alloc_uninit_slice::<T>(len);
+        ptr::write_bytes(p.as_mut_ptr(), 0, len);
+        slice::from_raw_parts_mut(p.as_mut_ptr() as *mut T, len)
+    }
+
+    /// Allocate the result of the fallible constructor `f` in this `Bump`.
+    ///
+    /// The result of `f` is written directly into space reserved in the arena.
+    /// If `f` returns `Ok`, an exclusive reference to the value is returned. If
+    /// it returns `Err`, the error is returned and, as long as nothing else was
+    /// allocated in this arena in the meantime, the reserved space is released
+    /// to be used by the next allocation.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for `Result<T, E>` would cause an overflow.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    ///
+    /// let x = bump.alloc_try_with(|| "42".parse::<u32>());
+    /// assert_eq!(x.map(|x| *x), Ok(42));
+    ///
+    /// let y = bump.alloc_try_with(|| "nope".parse::<u32>());
+    /// assert!(y.is_err());
+    /// ```
+    #[inline(always)]
+    pub fn alloc_try_with<F, T, E>(&self, f: F) -> Result<&mut T, E>
+    where
+        F: FnOnce() -> Result<T, E>,
+    {
+        let layout = Layout::new::<Result<T, E>>();
+
+        unsafe {
+            let p = self.alloc_layout(layout);
+            let p = p.as_ptr() as *mut Result<T, E>;
+            ptr::write(p, f());
+            match &mut *p {
+                Ok(val) => Ok(val),
+                Err(e) => {
+                    let e = ptr::read(e);
+                    self.try_rollback(p as *mut u8, layout.size());
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    /// Allocate a slice in this `Bump` and fill it with the `Ok` values yielded
+    /// by `iter`.
+    ///
+    /// If `iter` yields an `Err`, the values that were already written to the
+    /// slice are dropped and the error is returned. As long as nothing else
+    /// was allocated in this arena in the meantime, the slice's space is
+    /// released to be used by the next allocation.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for the slice would cause an overflow, or if
+    /// `iter` yields fewer items than its `len` reported.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    ///
+    /// let xs = bump.alloc_slice_try_fill_iter(["1", "2", "3"].iter().map(|s| s.parse::<u32>()));
+    /// assert_eq!(*xs.unwrap(), [1, 2, 3]);
+    ///
+    /// let ys = bump.alloc_slice_try_fill_iter(["1", "two"].iter().map(|s| s.parse::<u32>()));
+    /// assert!(ys.is_err());
+    /// ```
+    pub fn alloc_slice_try_fill_iter<T, I, E>(&self, iter: I) -> Result<&mut [T], E>
+    where
+        I: IntoIterator<Item = Result<T, E>>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let mut iter = iter.into_iter();
+        let len = iter.len();
+        let slice = self.alloc_uninit_slice::<T>(len);
+        let p = slice.as_mut_ptr() as *mut T;
+
+        unsafe {
+            for i in 0..len {
+                match iter.next().expect("Iterator supplied too few elements") {
+                    Ok(val) => ptr::write(p.add(i), val),
+                    Err(e) => {
+                        ptr::drop_in_place(ptr::slice_from_raw_parts_mut(p, i));
+                        self.try_rollback(p as *mut u8, len * mem::size_of::<T>());
+                        return Err(e);
+                    }
+                }
+            }
+            Ok(slice::from_raw_parts_mut(p, len))
+        }
+    }
+
+    /// Allocate `header` followed by a copy of `src` in this `Bump`, as a
+    /// single allocation, and return an exclusive reference to the resulting
+    /// [`HeaderSlice`](./struct.HeaderSlice.html).
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for the header and slice would cause an
+    /// overflow.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let node = bump.alloc_header_slice_copy("numbers", &[1, 2, 3]);
+    /// assert_eq!(node.header, "numbers");
+    /// assert_eq!(node.slice, [1, 2, 3]);
+    /// ```
+    #[inline(always)]
+    pub fn alloc_header_slice_copy<H, T>(&self, header: H, src: &[T]) -> &mut HeaderSlice<H, T>
+    where
+        T: Copy,
+    {
+        unsafe {
+            let (p, elems) = self.alloc_header_slice_raw::<H, T>(header, src.len());
+            ptr::copy_nonoverlapping(src.as_ptr(), elems, src.len());
+            &mut *(ptr::slice_from_raw_parts_mut(p, src.len()) as *mut HeaderSlice<H, T>)
+        }
+    }
+
+    /// Allocate `header` followed by a slice filled with the values yielded
+    /// by `iter` in this `Bump`, as a single allocation, and return an
+    /// exclusive reference to the resulting
+    /// [`HeaderSlice`](./struct.HeaderSlice.html).
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for the header and slice would cause an
+    /// overflow, or if `iter` yields fewer items than its `len` reported.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let node = bump.alloc_header_slice_fill_iter(3_usize, (1..4).map(|i| i * 10));
+    /// assert_eq!(node.header, node.slice.len());
+    /// assert_eq!(node.slice, [10, 20, 30]);
+    /// ```
+    pub fn alloc_header_slice_fill_iter<H, T, I>(
+        &self,
+        header: H,
+        iter: I,
+    ) -> &mut HeaderSlice<H, T>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let mut iter = iter.into_iter();
+        let len = iter.len();
+
+        unsafe {
+            let (p, elems) = self.alloc_header_slice_raw::<H, T>(header, len);
+            for i in 0..len {
+                let val = iter.next().expect("Iterator supplied too few elements");
+                ptr::write(elems.add(i), val);
+            }
+            &mut *(ptr::slice_from_raw_parts_mut(p, len) as *mut HeaderSlice<H, T>)
+        }
+    }
+
+    // Allocate space for a `HeaderSlice<H, T>` with `len` elements and write
+    // `header` into it. Returns pointers to the start of the allocation and to
+    // its uninitialized elements.
+    unsafe fn alloc_header_slice_raw<H, T>(&self, header: H, len: usize) -> (*mut T, *mut T) {
+        // This is the same layout that `#[repr(C)]` gives `HeaderSlice`.
+        let layout = Layout::array::<T>(len)
+            .and_then(|array| Layout::new::<H>().extend(array))
+            .map(|(layout, offset)| (layout.pad_to_align(), offset));
+        let (layout, offset) = match layout {
+            Ok(layout) => layout,
+            Err(_) => self.overflow(),
+        };
+
+        let p = self.alloc_layout(layout).as_ptr();
+        ptr::write(p as *mut H, header);
+        (p as *mut T, p.add(offset) as *mut T)
+    }
+
+    /// Lend out all of the remaining space in the current chunk as a
+    /// [`TailBuffer`](./struct.TailBuffer.html) that bytes can be written
+    /// into directly, without knowing their final size up front.
+    ///
+    /// Only the bytes that are actually written are kept allocated once the
+    /// buffer is finished. If the buffer fills up, it is moved to a fresh
+    /// chunk.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::fmt::Write;
+    ///
+    /// let bump = bumpalo::Bump::new();
+    ///
+    /// let mut buf = bump.tail_buffer();
+    /// write!(buf, "{} + {} = {}", 1, 2, 1 + 2).unwrap();
+    /// let s = buf.into_bump_str().unwrap();
+    ///
+    /// assert_eq!(s, "1 + 2 = 3");
+    /// ```
+    pub fn tail_buffer(&self) -> TailBuffer<'_, MIN_ALIGN> {
+        TailBuffer::new(self)
+    }
+
+    /// Format `args` into a string slice that is allocated inside this arena
+    /// with exactly the size of the formatted text.
+    ///
+    /// The text is first written directly into the free space at the end of
+    /// the current chunk. If it doesn't fit there, that first pass measures
+    /// its length instead, and a second pass writes it into a single
+    /// allocation of exactly that size. Either way, no intermediate buffer is
+    /// grown or left behind in the arena.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let s = bump.alloc_fmt(format_args!("{} + {} = {}", 1, 2, 1 + 2));
+    /// assert_eq!(s, "1 + 2 = 3");
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if a formatting trait implementation returns an error, if it
+    /// writes more text on the second pass than it did on the first, or if
+    /// reserving space for the text would cause an overflow.
+    pub fn alloc_fmt(&self, args: fmt::Arguments<'_>) -> &mut str {
+        unsafe {
+            let (tail, cap) = self.reserve_tail(0);
+            let mut writer = BoundedWriter::new(tail.as_ptr(), cap);
+            let result = fmt::Write::write_fmt(&mut writer, args);
+            if result.is_ok() && writer.fits() {
+                self.release_tail(tail, cap, writer.len);
+                return writer.into_str();
+            }
+            self.release_tail(tail, cap, 0);
+            result.expect("a formatting trait implementation returned an error");
+
+            // Now that we know the exact length, format again into a fresh
+            // allocation of that size.
+            let layout = match Layout::from_size_align(writer.len, 1) {
+                Ok(layout) => layout,
+                Err(_) => self.overflow(),
+            };
+            let p = self.alloc_layout(layout);
+            let mut writer = BoundedWriter::new(p.as_ptr(), layout.size());
+            fmt::Write::write_fmt(&mut writer, args)
+                .expect("a formatting trait implementation returned an error");
+            assert!(
+                writer.fits(),
+                "a formatting trait implementation wrote more text on its second pass"
+            );
+            writer.into_str()
+        }
+    }
+
+    /// Allocate space for an object with the given `Layout`.
+    ///
+    /// The returned pointer points at uninitialized memory, and should be
+    /// initialized with
+    /// [`std::ptr::write`](https://doc.rust-lang.org/stable/std/ptr/fn.write.html).
+    ///
+    /// ## Panics
+    ///
+    /// Panics if reserving space for `layout` would cause an overflow, or if
+    /// the global allocator fails to give us a new chunk.
+    #[inline(always)]
+    pub fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        match self.try_alloc_layout_fast(layout) {
+            Some(p) => p,
+            None => self.alloc_layout_slow(layout),
+        }
+    }
+
+    // Like `alloc_layout`, but returns an error instead of panicking if the
+    // global allocator fails to give us a new chunk, or if the allocation is
+    // too large.
+    #[inline(always)]
+    pub(crate) fn try_alloc_layout(&self, layout: Layout) -> Result<NonNull<u8>, alloc::AllocErr> {
+        match self.try_alloc_layout_fast(layout) {
+            Some(p) => Ok(p),
+            None => self.try_alloc_layout_slow(layout),
+        }
+    }
+
+    // Bump allocate in the current chunk, or return `None` if there is no
+    // current chunk or the allocation doesn't fit in it.
+    #[inline(always)]
+    fn try_alloc_layout_fast(&self, layout: Layout) -> Option<NonNull<u8>> {
+        unsafe {
+            let footer = self.current_chunk_footer.get()?;
+            let footer = footer.as_ref();
+            let ptr = footer.ptr.get().as_ptr() as usize;
+            debug_assert_eq!(ptr % MIN_ALIGN, 0, "The bump finger should be aligned");
+
+            // The finger is always aligned to `MIN_ALIGN`, so only round it up
+            // for allocations that need more alignment than that.
+            let ptr = if layout.align() <= MIN_ALIGN {
+                ptr
+            } else {
+                round_up_to(ptr, layout.align())
+            };
+            let end = footer as *const _ as usize;
+            debug_assert!(ptr <= end);
+
+            let size = Self::round_size_to_min_align(layout.size())?;
+            let new_ptr = ptr.checked_add(size)?;
+
+            if new_ptr <= end {
+                let p = ptr as *mut u8;
+                debug_assert!(new_ptr <= footer as *const _ as usize);
+                footer.ptr.set(NonNull::new_unchecked(new_ptr as *mut u8));
+                Some(NonNull::new_unchecked(p))
+            } else {
+                None
+            }
+        }
+    }
+
+    #[inline(never)]
+    #[cold]
+    fn overflow(&self) -> ! {
+        panic!("allocation too large, caused overflow")
+    }
+
+    // If the allocation of `size` bytes at `p` is still the most recent one in
+    // the current chunk, deallocate it by moving the bump finger back to `p`.
+    unsafe fn try_rollback(&self, p: *mut u8, size: usize) {
+        if let Some(footer) = self.current_chunk_footer.get() {
+            let footer = footer.as_ref();
+            let end = Self::round_size_to_min_align(size).map(|size| p as usize + size);
+            if end == Some(footer.ptr.get().as_ptr() as usize) {
+                footer.ptr.set(NonNull::new_unchecked(p));
+            }
+        }
+    }
 
-            // Set our current chunk's next link to this new chunk.
-            self.current_chunk_footer
-                .get()
-                .as_ref()
-                .next
-                .set(Some(footer));
+    // Allocate at least `min` bytes, extending the allocation to the end of the
+    // current chunk if it was made there. Returns the start and size of the
+    // allocation.
+    pub(crate) fn reserve_tail(&self, min: usize) -> (NonNull<u8>, usize) {
+        unsafe {
+            let p = self.alloc_layout(layout_from_size_align(min, 1));
+            let size = match Self::round_size_to_min_align(min) {
+                Some(size) => size,
+                None => self.overflow(),
+            };
+
+            if let Some(footer) = self.current_chunk_footer.get() {
+                let footer = footer.as_ref();
+                if footer.ptr.get().as_ptr() as usize == p.as_ptr() as usize + size {
+                    // Keep the bump finger aligned to `MIN_ALIGN`.
+                    let end = (footer as *const _ as usize) & !(MIN_ALIGN - 1);
+                    footer.ptr.set(NonNull::new_unchecked(end as *mut u8));
+                    return (p, end - p.as_ptr() as usize);
+                }
+            }
+
+            (p, size)
+        }
+    }
+
+    // Shrink an allocation of `size` bytes at `p` that was made by
+    // `reserve_tail` down to `used` bytes, if it is still the most recent
+    // allocation in the current chunk.
+    pub(crate) unsafe fn release_tail(&self, p: NonNull<u8>, size: usize, used: usize) {
+        debug_assert!(used <= size);
+        if let Some(footer) = self.current_chunk_footer.get() {
+            let footer = footer.as_ref();
+            if footer.ptr.get().as_ptr() as usize == p.as_ptr() as usize + size {
+                let used = round_up_to(used, MIN_ALIGN);
+                footer.ptr.set(NonNull::new_unchecked(p.as_ptr().add(used)));
+            }
+        }
+    }
+
+    // Slow path allocation for when we need to allocate a new chunk from the
+    // parent bump set because there isn't enough room in our current chunk.
+    #[inline(never)]
+    fn alloc_layout_slow(&self, layout: Layout) -> NonNull<u8> {
+        match self.try_alloc_layout_slow(layout) {
+            Ok(p) => p,
+            Err(alloc::AllocErr) if Self::chunk_overflows(layout) => self.overflow(),
+            Err(alloc::AllocErr) => alloc::handle_alloc_error(layout),
+        }
+    }
+
+    // Whether the size of a chunk that could hold an allocation of `layout`
+    // overflows, in which case the allocation is too large to ever succeed,
+    // rather than the global allocator having failed.
+    fn chunk_overflows(layout: Layout) -> bool {
+        Self::round_size_to_min_align(layout.size())
+            .and_then(|size| Layout::from_size_align(size, layout.align()).ok())
+            .and_then(Self::large_chunk_layout)
+            .is_none()
+    }
+
+    // The fallible version of `alloc_layout_slow`. Too large allocations are
+    // reported as errors too.
+    #[inline(never)]
+    fn try_alloc_layout_slow(&self, layout: Layout) -> Result<NonNull<u8>, alloc::AllocErr> {
+        unsafe {
+            let size = Self::round_size_to_min_align(layout.size()).ok_or(alloc::AllocErr)?;
+            let layout =
+                Layout::from_size_align(size, layout.align()).map_err(|_| alloc::AllocErr)?;
+
+            if size > LARGE_OBJECT_THRESHOLD {
+                return self.try_alloc_layout_large(layout);
+            }
+
+            let current = self.current_chunk_footer.get();
+
+            // Get a new chunk from the global allocator.
+            let current_layout = current.map(|f| f.as_ref().layout.clone());
+            let chunk_layout =
+                Self::new_chunk_layout(current_layout, layout).ok_or(alloc::AllocErr)?;
+            let footer = Self::alloc_chunk(chunk_layout).ok_or(alloc::AllocErr)?;
+
+            match current {
+                // Set our current chunk's next link to this new chunk.
+                Some(current) => current.as_ref().next.set(Some(footer)),
+                // This is our first regular chunk. Put it at the end of our
+                // list of all chunks, after any large-object chunks.
+                None => match self.all_chunk_footers.get() {
+                    Some(head) => chunk_list_tail(head).as_ref().next.set(Some(footer)),
+                    None => self.all_chunk_footers.set(Some(footer)),
+                },
+            }
 
             // Set the new chunk as our new current chunk.
-            self.current_chunk_footer.set(footer);
+            self.current_chunk_footer.set(Some(footer));
 
             // Move the bump ptr finger ahead to allocate room for `val`.
             let footer = footer.as_ref();
@@ -503,15 +1606,103 @@ impl Bump {
             footer.ptr.set(NonNull::new_unchecked(ptr as *mut u8));
 
             // Return a pointer to the start of this chunk.
-            footer.data.cast::<u8>()
+            Ok(footer.data.cast::<u8>())
         }
     }
 
+    // Slow path allocation for requests that are too large to share a chunk
+    // with other allocations. The request gets an exactly-sized chunk of its
+    // own, which is linked in at the head of our list of all chunks, so that
+    // the current chunk stays the tail. It is freed on the next reset.
+    #[inline(never)]
+    fn try_alloc_layout_large(&self, layout: Layout) -> Result<NonNull<u8>, alloc::AllocErr> {
+        unsafe {
+            let chunk_layout = Self::large_chunk_layout(layout).ok_or(alloc::AllocErr)?;
+            let footer = Self::alloc_chunk(chunk_layout).ok_or(alloc::AllocErr)?;
+            footer.as_ref().next.set(self.all_chunk_footers.get());
+            self.all_chunk_footers.set(Some(footer));
+
+            // The whole chunk is used up by this allocation.
+            let footer = footer.as_ref();
+            let ptr = footer.data.as_ptr() as usize + layout.size();
+            debug_assert!(ptr <= footer as *const _ as usize);
+            footer.ptr.set(NonNull::new_unchecked(ptr as *mut u8));
+
+            Ok(footer.data)
+        }
+    }
+
+    /// Iterate over each chunk of memory that this arena has bump allocated
+    /// into.
+    ///
+    /// Each chunk's allocated bytes are given as `MaybeUninit<u8>`, since
+    /// there may be uninitialized padding between allocations, along with the
+    /// chunk's capacity.
+    ///
+    /// The chunks that were bump allocated into by this arena are visited in
+    /// order of allocation: oldest chunks first, newest chunks last. They are
+    /// preceded by chunks that were dedicated to a single large allocation or
+    /// that were [`absorb`](#method.absorb)ed from other arenas, most recent
+    /// first.
+    ///
+    /// Because this method takes `&mut self`, there can't be any active
+    /// references to the objects allocated in this arena while the chunks are
+    /// being read.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let mut bump = bumpalo::Bump::new();
+    /// for i in 0..10_000_u64 {
+    ///     bump.alloc(i);
+    /// }
+    ///
+    /// let mut used = 0;
+    /// for chunk in bump.iter_allocated_chunks() {
+    ///     assert!(chunk.bytes.len() <= chunk.capacity);
+    ///     used += chunk.bytes.len();
+    /// }
+    /// assert_eq!(used, 10_000 * 8);
+    /// ```
+    pub fn iter_allocated_chunks(&mut self) -> AllocatedChunks<'_> {
+        unsafe { AllocatedChunks::new(self.all_chunk_footers.get()) }
+    }
+
+    /// Iterate over raw information about each chunk of memory that this
+    /// arena has bump allocated into, in the same order as
+    /// [`iter_allocated_chunks`](#method.iter_allocated_chunks).
+    ///
+    /// Unlike `iter_allocated_chunks`, this only takes `&self`, so it can be
+    /// used for diagnostics while references to allocated objects are live.
+    /// It is up to the caller to not read through the raw pointers in a way
+    /// that conflicts with those references.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let x = bump.alloc(42_u64);
+    ///
+    /// let chunks: Vec<_> = bump.iter_allocated_chunks_raw().collect();
+    /// assert_eq!(chunks.len(), 1);
+    /// assert_eq!(chunks[0].start, x as *mut u64 as *const u8);
+    /// assert_eq!(chunks[0].used, 8);
+    /// ```
+    pub fn iter_allocated_chunks_raw(&self) -> RawAllocatedChunks<'_> {
+        unsafe { RawAllocatedChunks::new(self.all_chunk_footers.get()) }
+    }
+
     /// Call `f` on each chunk of allocated memory that this arena has bump
     /// allocated into.
     ///
-    /// `f` is invoked in order of allocation: oldest chunks first, newest
-    /// chunks last.
+    /// Deprecated in favor of the safe
+    /// [`iter_allocated_chunks`](#method.iter_allocated_chunks).
+    ///
+    /// The chunks that were bump allocated into by this arena are visited in
+    /// order of allocation: oldest chunks first, newest chunks last. They are
+    /// preceded by chunks that were dedicated to a single large allocation or
+    /// that were [`absorb`](#method.absorb)ed from other arenas, most recent
+    /// first.
     ///
     /// ## Safety
     ///
@@ -539,6 +1730,7 @@ impl Bump {
     /// ## Example
     ///
     /// ```
+    /// # #![allow(deprecated)]
     /// let mut bump = bumpalo::Bump::new();
     ///
     /// // Allocate a bunch of things in this bump arena, potentially causing
@@ -555,11 +1747,12 @@ impl Bump {
     ///     });
     /// }
     /// ```
+    #[deprecated(note = "use `iter_allocated_chunks` instead")]
     pub unsafe fn each_allocated_chunk<F>(&mut self, mut f: F)
     where
         F: for<'a> FnMut(&'a [u8]),
     {
-        let mut footer = Some(self.all_chunk_footers.get());
+        let mut footer = self.all_chunk_footers.get();
         while let Some(foot) = footer {
             let foot = foot.as_ref();
 
@@ -580,12 +1773,130 @@ impl Bump {
             footer = foot.next.get();
         }
     }
+
+    /// Returns `true` if `ptr` points into memory that has been allocated in
+    /// this arena.
+    ///
+    /// This walks the list of this arena's chunks, so it takes time linear in
+    /// the number of chunks. For arenas with many chunks, build a
+    /// [`ChunkIndex`](./struct.ChunkIndex.html) with
+    /// [`chunk_index`](#method.chunk_index) instead.
+    ///
+    /// Pointers to zero-sized values, and pointers one past the end of an
+    /// allocation, may not be reported as contained.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let x = bump.alloc(1_u32);
+    /// let y = Box::new(2_u32);
+    ///
+    /// assert!(bump.contains(x as *const u32 as *const u8));
+    /// assert!(!bump.contains(&*y as *const u32 as *const u8));
+    /// ```
+    pub fn contains(&self, ptr: *const u8) -> bool {
+        let ptr = ptr as usize;
+        self.chunk_footers()
+            .any(|f| unsafe { chunk_allocated_range(f.as_ref()).contains(&ptr) })
+    }
+
+    /// Build a [`ChunkIndex`](./struct.ChunkIndex.html) of this arena's
+    /// chunks, which answers [`contains`](#method.contains) queries in time
+    /// logarithmic in the number of chunks.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let xs: Vec<_> = (0..10_000).map(|i| bump.alloc(i) as *const i32 as *const u8).collect();
+    ///
+    /// let index = bump.chunk_index();
+    /// assert!(xs.iter().all(|&x| index.contains(x)));
+    /// ```
+    pub fn chunk_index(&self) -> ChunkIndex<'_, MIN_ALIGN> {
+        ChunkIndex::new(self)
+    }
+
+    // Walk this arena's chunks, most recently linked in first.
+    pub(crate) fn chunk_footers(&self) -> impl Iterator<Item = NonNull<ChunkFooter>> + '_ {
+        let mut footer = self.all_chunk_footers.get();
+        core::iter::from_fn(move || {
+            let f = footer?;
+            footer = unsafe { f.as_ref().next.get() };
+            Some(f)
+        })
+    }
+
+    // A snapshot of the ends of this arena's chunk list, which changes whenever
+    // a chunk is added to the arena.
+    pub(crate) fn chunk_list_ends(
+        &self,
+    ) -> (Option<NonNull<ChunkFooter>>, Option<NonNull<ChunkFooter>>) {
+        (
+            self.all_chunk_footers.get(),
+            self.current_chunk_footer.get(),
+        )
+    }
+}
+
+// Evaluating `NoDropGlue::<T>::ASSERT` fails to compile if `T` needs to be
+// dropped.
+struct NoDropGlue<T>(PhantomData<T>);
+
+impl<T> NoDropGlue<T> {
+    const ASSERT: () = assert!(
+        !mem::needs_drop::<T>(),
+        "pinned values in a `Bump` must not need to be dropped"
+    );
+}
+
+// A `fmt::Write` implementation for `Bump::alloc_fmt` that writes into `cap`
+// bytes at `ptr` for as long as the text fits, and after that only keeps
+// counting its length.
+struct BoundedWriter {
+    ptr: *mut u8,
+    cap: usize,
+    len: usize,
+}
+
+impl BoundedWriter {
+    fn new(ptr: *mut u8, cap: usize) -> BoundedWriter {
+        BoundedWriter { ptr, cap, len: 0 }
+    }
+
+    // Whether all of the text written so far was stored.
+    fn fits(&self) -> bool {
+        self.len <= self.cap
+    }
+
+    // Safety: `self.fits()` must be true, and the bytes at `ptr` must live for
+    // `'a` without being aliased.
+    unsafe fn into_str<'a>(self) -> &'a mut str {
+        debug_assert!(self.fits());
+        str::from_utf8_unchecked_mut(slice::from_raw_parts_mut(self.ptr, self.len))
+    }
+}
+
+impl fmt::Write for BoundedWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let new_len = self.len.saturating_add(s.len());
+        // Once a piece didn't fit, `len` stays past `cap`, so nothing after it
+        // is stored either, and the stored text is always whole pieces.
+        if new_len <= self.cap {
+            unsafe {
+                ptr::copy_nonoverlapping(s.as_ptr(), self.ptr.add(self.len), s.len());
+            }
+        }
+        self.len = new_len;
+        Ok(())
+    }
 }
 
-unsafe impl<'a> alloc::Alloc for &'a Bump {
+unsafe impl<'a, const MIN_ALIGN: usize> alloc::Alloc for &'a Bump<MIN_ALIGN> {
     #[inline(always)]
     unsafe fn alloc(&mut self, layout: Layout) -> Result<NonNull<u8>, alloc::AllocErr> {
-        Ok(self.alloc_layout(layout))
+        self.try_alloc_layout(layout)
     }
 
     #[inline(always)]
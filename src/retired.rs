@@ -0,0 +1,123 @@
+use crate::imports::*;
+use crate::{dealloc_chunk_list, ChunkFooter};
+
+/// Chunks that have been taken out of a `Bump`, but not yet returned to the
+/// global allocator.
+///
+/// Deallocating a large arena means calling `dealloc` once for each of its
+/// chunks. A `RetiredChunks` lets that work be moved off of latency-sensitive
+/// code: it can be sent to another thread, stashed in a queue and drained
+/// later, or handed to the shared background reclaimer with
+/// [`dealloc_deferred`](#method.dealloc_deferred). The chunks are deallocated
+/// when the `RetiredChunks` is dropped.
+///
+/// Get one from [`Bump::retire`](./struct.Bump.html#method.retire) or
+/// [`Bump::reset_retire`](./struct.Bump.html#method.reset_retire).
+///
+/// ## Example
+///
+/// ```
+/// use std::sync::mpsc;
+/// use std::thread;
+///
+/// let (send, recv) = mpsc::channel();
+/// let reclaimer = thread::spawn(move || {
+///     for retired in recv {
+///         drop(retired);
+///     }
+/// });
+///
+/// for _ in 0..10 {
+///     let bump = bumpalo::Bump::new();
+///     for i in 0..1000 {
+///         bump.alloc(i);
+///     }
+///     send.send(bump.retire()).unwrap();
+/// }
+///
+/// drop(send);
+/// reclaimer.join().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct RetiredChunks {
+    // The head of an intrusive linked list of chunks that we exclusively own.
+    head: Option<NonNull<ChunkFooter>>,
+}
+
+// Nothing can borrow from retired chunks, so they may be freed on any thread.
+unsafe impl Send for RetiredChunks {}
+
+impl RetiredChunks {
+    // Take ownership of the chunk list starting at `head`.
+    #[inline]
+    pub(crate) unsafe fn from_list(head: Option<NonNull<ChunkFooter>>) -> RetiredChunks {
+        RetiredChunks { head }
+    }
+
+    /// Returns `true` if there are no chunks to deallocate.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Returns the number of chunks waiting to be deallocated.
+    pub fn len(&self) -> usize {
+        let mut len = 0;
+        let mut footer = self.head;
+        while let Some(f) = footer {
+            len += 1;
+            footer = unsafe { f.as_ref().next.get() };
+        }
+        len
+    }
+
+    /// Deallocate these chunks on a shared background thread instead of on
+    /// this one.
+    ///
+    /// The background thread is spawned the first time it is needed. If it
+    /// can't be spawned, the chunks are deallocated on this thread instead.
+    #[cfg(feature = "std")]
+    pub fn dealloc_deferred(self) {
+        if self.is_empty() {
+            return;
+        }
+        if let Some(reclaimer) = reclaimer() {
+            // If the reclaimer thread has gone away, the chunks are sent back
+            // to us in the error and deallocated here.
+            let _ = reclaimer.send(self);
+        }
+    }
+}
+
+impl Drop for RetiredChunks {
+    fn drop(&mut self) {
+        unsafe {
+            dealloc_chunk_list(self.head.take());
+        }
+    }
+}
+
+// The sending half of the channel to the background reclaimer thread, or `None`
+// if the thread could not be spawned.
+#[cfg(feature = "std")]
+fn reclaimer() -> Option<&'static std::sync::mpsc::Sender<RetiredChunks>> {
+    use std::sync::{mpsc, OnceLock};
+    use std::thread;
+
+    static RECLAIMER: OnceLock<Option<mpsc::Sender<RetiredChunks>>> = OnceLock::new();
+
+    RECLAIMER
+        .get_or_init(|| {
+            let (send, recv) = mpsc::channel::<RetiredChunks>();
+            thread::Builder::new()
+                .name("bumpalo-reclaimer".into())
+                .spawn(move || {
+                    for retired in recv {
+                        drop(retired);
+                    }
+                })
+                .ok()
+                .map(|_| send)
+        })
+        .as_ref()
+}
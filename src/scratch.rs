@@ -0,0 +1,157 @@
+//! Per-thread scratch arenas.
+//!
+//! Creating a `Bump` for the duration of a single function call means asking
+//! the global allocator for a fresh chunk every time. Instead, [`with`] lends
+//! out an arena from a per-thread cache and [`reset`]s it when the closure
+//! returns, so the same chunk memory is reused from call to call.
+//!
+//! Nested calls to [`with`] are each given their own arena, so an inner scope
+//! can never clobber the allocations of an outer one.
+//!
+//! ## Example
+//!
+//! ```
+//! let len = bumpalo::scratch::with(|bump| {
+//!     let xs = bump.alloc([1, 2, 3]);
+//!
+//!     // Nested scratch arenas don't interfere with the outer one.
+//!     let y = bumpalo::scratch::with(|inner| *inner.alloc(xs[0] + xs[1]));
+//!
+//!     assert_eq!(*xs, [1, 2, 3]);
+//!     y as usize + xs.len()
+//! });
+//! assert_eq!(len, 6);
+//! ```
+//!
+//! [`with`]: ./fn.with.html
+//! [`reset`]: ../struct.Bump.html#method.reset
+
+use crate::Bump;
+use std::cell::RefCell;
+
+// Retain up to one mebibyte of chunk memory per thread by default.
+const DEFAULT_MAX_RETAINED_BYTES: usize = 1 << 20;
+
+struct Cache {
+    // Reset arenas that are ready to be lent out again.
+    arenas: Vec<Bump>,
+
+    // The total size of the chunks owned by `arenas`.
+    retained_bytes: usize,
+
+    // The limit on `retained_bytes`.
+    max_retained_bytes: usize,
+}
+
+impl Bump {
+    // The total size of all of this arena's chunks, including their footers.
+    fn chunk_bytes(&self) -> usize {
+        let mut bytes = 0;
+        let mut footer = self.all_chunk_footers.get();
+        while let Some(f) = footer {
+            unsafe {
+                bytes += f.as_ref().layout.size();
+                footer = f.as_ref().next.get();
+            }
+        }
+        bytes
+    }
+}
+
+impl Cache {
+    fn pop(&mut self) -> Option<Bump> {
+        let bump = self.arenas.pop()?;
+        self.retained_bytes -= bump.chunk_bytes();
+        Some(bump)
+    }
+
+    fn push(&mut self, bump: Bump) {
+        let bytes = bump.chunk_bytes();
+        if self.retained_bytes + bytes <= self.max_retained_bytes {
+            self.retained_bytes += bytes;
+            self.arenas.push(bump);
+        }
+    }
+
+    fn trim(&mut self) {
+        while self.retained_bytes > self.max_retained_bytes {
+            // Drop the arenas that were cached first, which are the least
+            // likely to be reused soon.
+            let bump = self.arenas.remove(0);
+            self.retained_bytes -= bump.chunk_bytes();
+        }
+    }
+}
+
+thread_local! {
+    static CACHE: RefCell<Cache> = const {
+        RefCell::new(Cache {
+            arenas: Vec::new(),
+            retained_bytes: 0,
+            max_retained_bytes: DEFAULT_MAX_RETAINED_BYTES,
+        })
+    };
+}
+
+/// Call `f` with a scratch arena from this thread's cache.
+///
+/// The arena is reset once `f` returns, and then returned to the cache,
+/// unless that would make the cache retain more than its configured number of
+/// bytes (see [`set_max_retained_bytes`]). Nothing allocated in the arena can
+/// escape `f`.
+///
+/// Calls to `with` may be nested, and each nested call is given a different
+/// arena.
+///
+/// ## Example
+///
+/// ```
+/// let sum = bumpalo::scratch::with(|bump| {
+///     let squares = bump.alloc([1 * 1, 2 * 2, 3 * 3]);
+///     squares.iter().sum::<i32>()
+/// });
+/// assert_eq!(sum, 14);
+/// ```
+///
+/// [`set_max_retained_bytes`]: ./fn.set_max_retained_bytes.html
+pub fn with<F, R>(f: F) -> R
+where
+    F: FnOnce(&Bump) -> R,
+{
+    // If this thread's cache has already been destroyed, fall back to an
+    // uncached arena.
+    let mut bump = CACHE
+        .try_with(|cache| cache.borrow_mut().pop())
+        .ok()
+        .and_then(|bump| bump)
+        .unwrap_or_else(Bump::new);
+
+    let result = f(&bump);
+
+    bump.reset();
+    let _ = CACHE.try_with(move |cache| cache.borrow_mut().push(bump));
+
+    result
+}
+
+/// Set the maximum number of bytes of chunk memory that this thread's scratch
+/// arena cache retains between uses of [`with`].
+///
+/// Arenas that would push the cache over this limit are returned to the
+/// global allocator instead of being cached. The default is one mebibyte.
+///
+/// ## Example
+///
+/// ```
+/// // Don't keep any scratch memory around on this thread.
+/// bumpalo::scratch::set_max_retained_bytes(0);
+/// ```
+///
+/// [`with`]: ./fn.with.html
+pub fn set_max_retained_bytes(bytes: usize) {
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        cache.max_retained_bytes = bytes;
+        cache.trim();
+    });
+}
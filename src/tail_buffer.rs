@@ -0,0 +1,238 @@
+use crate::imports::*;
+use crate::Bump;
+use core::str;
+
+/// A growable byte buffer that is written directly into the free space at the
+/// end of a `Bump`'s current chunk.
+///
+/// When the buffer is created, it reserves all of the space that is left in
+/// the current chunk. Bytes are written into that space with
+/// [`extend_from_slice`](#method.extend_from_slice), the `io::Write` and
+/// `fmt::Write` implementations, or directly through
+/// [`spare_capacity_mut`](#method.spare_capacity_mut) and
+/// [`commit`](#method.commit). When the buffer runs out of space, its contents
+/// are moved to a fresh chunk that is at least twice as large.
+///
+/// Finishing the buffer with [`into_bump_slice`](#method.into_bump_slice) or
+/// [`into_bump_str`](#method.into_bump_str) keeps only the bytes that were
+/// written, and returns the rest of the reserved space to the arena, as long
+/// as nothing else has been allocated in the arena in the meantime. Dropping
+/// the buffer returns all of its space in the same way.
+///
+/// Get one with [`Bump::tail_buffer`](./struct.Bump.html#method.tail_buffer).
+///
+/// ## Example
+///
+/// ```
+/// use std::io::Write;
+///
+/// let bump = bumpalo::Bump::new();
+///
+/// let mut buf = bump.tail_buffer();
+/// for i in 0..3 {
+///     write!(buf, "{},", i).unwrap();
+/// }
+/// let bytes = buf.into_bump_slice();
+///
+/// assert_eq!(bytes, b"0,1,2,");
+/// ```
+#[derive(Debug)]
+pub struct TailBuffer<'bump, const MIN_ALIGN: usize = 1> {
+    bump: &'bump Bump<MIN_ALIGN>,
+
+    // The start of the space reserved in `bump`.
+    ptr: NonNull<u8>,
+
+    // The number of bytes that have been written.
+    len: usize,
+
+    // The number of bytes reserved at `ptr`.
+    cap: usize,
+}
+
+impl<'bump, const MIN_ALIGN: usize> TailBuffer<'bump, MIN_ALIGN> {
+    pub(crate) fn new(bump: &'bump Bump<MIN_ALIGN>) -> Self {
+        let (ptr, cap) = bump.reserve_tail(0);
+        TailBuffer {
+            bump,
+            ptr,
+            len: 0,
+            cap,
+        }
+    }
+
+    /// Returns the number of bytes that have been written to this buffer.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no bytes have been written to this buffer.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of bytes this buffer can hold without moving.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    /// Returns the bytes that have been written to this buffer.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Returns the unwritten space at the end of this buffer.
+    ///
+    /// After writing into the start of this space, call
+    /// [`commit`](#method.commit) to add the written bytes to the buffer.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let mut buf = bump.tail_buffer();
+    ///
+    /// buf.reserve(4);
+    /// for (dst, src) in buf.spare_capacity_mut().iter_mut().zip(b"abcd") {
+    ///     dst.write(*src);
+    /// }
+    /// unsafe { buf.commit(4) };
+    ///
+    /// assert_eq!(buf.as_slice(), b"abcd");
+    /// ```
+    #[inline]
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<u8>] {
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.ptr.as_ptr().add(self.len) as *mut MaybeUninit<u8>,
+                self.cap - self.len,
+            )
+        }
+    }
+
+    /// Add the first `n` bytes of the unwritten space to the written bytes of
+    /// this buffer.
+    ///
+    /// ## Safety
+    ///
+    /// The first `n` bytes of
+    /// [`spare_capacity_mut`](#method.spare_capacity_mut) must have been
+    /// initialized, and `n` must not be larger than its length.
+    #[inline]
+    pub unsafe fn commit(&mut self, n: usize) {
+        debug_assert!(n <= self.cap - self.len);
+        self.len += n;
+    }
+
+    /// Make sure that at least `additional` more bytes can be written without
+    /// moving this buffer.
+    ///
+    /// If there isn't enough room left, the written bytes are moved to a fresh
+    /// allocation that is at least twice as large.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        if self.cap - self.len < additional {
+            self.grow(additional);
+        }
+    }
+
+    #[inline(never)]
+    #[cold]
+    fn grow(&mut self, additional: usize) {
+        let min_cap = self.len.checked_add(additional).expect("capacity overflow");
+        let new_cap = cmp::max(min_cap, self.cap.saturating_mul(2));
+
+        unsafe {
+            // Give back our old space first, so that it can be reused if our
+            // new space ends up elsewhere. Its contents stay intact until we
+            // have copied them over.
+            self.bump.release_tail(self.ptr, self.cap, 0);
+            let (ptr, cap) = self.bump.reserve_tail(new_cap);
+            ptr::copy(self.ptr.as_ptr(), ptr.as_ptr(), self.len);
+            self.ptr = ptr;
+            self.cap = cap;
+        }
+    }
+
+    /// Append all of the bytes in `bytes` to this buffer.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// let bump = bumpalo::Bump::new();
+    /// let mut buf = bump.tail_buffer();
+    ///
+    /// buf.extend_from_slice(b"hello, ");
+    /// buf.extend_from_slice(b"world");
+    ///
+    /// assert_eq!(buf.as_slice(), b"hello, world");
+    /// ```
+    #[inline]
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+        self.reserve(bytes.len());
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), self.ptr.as_ptr().add(self.len), bytes.len());
+        }
+        self.len += bytes.len();
+    }
+
+    /// Finish this buffer, keeping the written bytes allocated in the arena,
+    /// and return them.
+    pub fn into_bump_slice(self) -> &'bump mut [u8] {
+        unsafe {
+            self.bump.release_tail(self.ptr, self.cap, self.len);
+            let bytes = slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len);
+            mem::forget(self);
+            bytes
+        }
+    }
+
+    /// Finish this buffer, keeping the written bytes allocated in the arena,
+    /// and return them as a string.
+    ///
+    /// Returns an error if the written bytes are not valid UTF-8. They stay
+    /// allocated in the arena either way.
+    pub fn into_bump_str(self) -> Result<&'bump mut str, str::Utf8Error> {
+        str::from_utf8_mut(self.into_bump_slice())
+    }
+}
+
+impl<'bump, const MIN_ALIGN: usize> Drop for TailBuffer<'bump, MIN_ALIGN> {
+    fn drop(&mut self) {
+        unsafe {
+            self.bump.release_tail(self.ptr, self.cap, 0);
+        }
+    }
+}
+
+impl<'bump, const MIN_ALIGN: usize> fmt::Write for TailBuffer<'bump, MIN_ALIGN> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'bump, const MIN_ALIGN: usize> std::io::Write for TailBuffer<'bump, MIN_ALIGN> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.extend_from_slice(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
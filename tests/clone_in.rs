@@ -0,0 +1,96 @@
+extern crate bumpalo;
+use bumpalo::collections::{String, ToStringIn, Vec};
+use bumpalo::{Bump, CloneIn};
+
+#[derive(Debug, PartialEq)]
+struct Node<'a> {
+    name: &'a str,
+    children: Vec<'a, &'a Node<'a>>,
+}
+
+// What a derived implementation would look like for a small AST node.
+impl<'old, 'new> CloneIn<'new> for Node<'old> {
+    type Cloned = Node<'new>;
+
+    fn clone_in(&self, bump: &'new Bump) -> Node<'new> {
+        Node {
+            name: self.name.clone_in(bump),
+            children: self.children.clone_in(bump),
+        }
+    }
+}
+
+#[test]
+fn clone_primitives_options_and_tuples() {
+    let b = Bump::new();
+    assert_eq!(5_u32.clone_in(&b), 5);
+    assert_eq!('x'.clone_in(&b), 'x');
+    assert_eq!(Some(1.5_f64).clone_in(&b), Some(1.5));
+    assert_eq!(None::<u8>.clone_in(&b), None);
+    assert_eq!((1_u8, true, ()).clone_in(&b), (1, true, ()));
+}
+
+#[test]
+fn clone_references_into_other_arena() {
+    let old = Bump::new();
+    let new = Bump::new();
+
+    let x: &u64 = old.alloc(7);
+    // Method calls would find `u64`'s implementation first.
+    let y = CloneIn::clone_in(&x, &new);
+    assert_eq!(*y, 7);
+    assert!(new.contains(y as *const u64 as *const u8));
+
+    let s: &str = old.alloc_fmt(format_args!("hello"));
+    let t = s.clone_in(&new);
+    assert_eq!(t, "hello");
+    assert!(new.contains(t.as_ptr()));
+
+    let xs: &[&str] = &["a", "b"];
+    let ys = xs.clone_in(&new);
+    assert_eq!(ys, ["a", "b"]);
+    assert!(new.contains(ys.as_ptr() as *const u8));
+    assert!(new.contains(ys[1].as_ptr()));
+}
+
+#[test]
+fn clone_collections_into_other_arena() {
+    let new = Bump::new();
+    let (v, s) = {
+        let old = Bump::new();
+        let v = bumpalo::vec![in &old; String::from_str_in("one", &old), "two".to_string_in(&old)];
+        let s = String::from_str_in("text", &old);
+        (v.clone_in(&new), s.clone_in(&new))
+    };
+    assert_eq!(
+        v,
+        [
+            String::from_str_in("one", &new),
+            String::from_str_in("two", &new)
+        ]
+    );
+    assert_eq!(v.capacity(), 2);
+    assert!(new.contains(v[0].as_ptr()));
+    assert_eq!(s, "text");
+}
+
+#[test]
+fn clone_tree_into_other_arena() {
+    let new = Bump::new();
+    let tree = {
+        let old = Bump::new();
+        let leaf = old.alloc(Node {
+            name: old.alloc_fmt(format_args!("leaf")),
+            children: Vec::new_in(&old),
+        });
+        let root = Node {
+            name: "root",
+            children: bumpalo::vec![in &old; &*leaf, &*leaf],
+        };
+        root.clone_in(&new)
+    };
+    assert_eq!(tree.name, "root");
+    assert_eq!(tree.children.len(), 2);
+    assert_eq!(tree.children[1].name, "leaf");
+    assert!(new.contains(tree.children[0].name.as_ptr()));
+}
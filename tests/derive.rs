@@ -0,0 +1,149 @@
+#![cfg(feature = "derive")]
+
+extern crate bumpalo;
+
+use bumpalo::collections::{String, Vec};
+use bumpalo::{Bump, CloneIn};
+use std::fmt::Debug;
+
+#[derive(CloneIn, Debug, PartialEq)]
+enum Expr<'bump> {
+    Number(u64),
+    Name(&'bump str),
+    Call {
+        callee: &'bump Expr<'bump>,
+        args: Vec<'bump, Expr<'bump>>,
+    },
+    Nothing,
+}
+
+#[derive(CloneIn, Debug, PartialEq)]
+struct Item<'bump> {
+    label: String<'bump>,
+    value: Option<&'bump Expr<'bump>>,
+    span: (u32, u32),
+}
+
+#[derive(CloneIn, Debug, PartialEq)]
+struct Pair<'bump, T: Debug, U>(&'bump T, U)
+where
+    U: PartialEq;
+
+// Borrowed from something that outlives every arena, so it isn't copied.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Interned<'s>(&'s str);
+
+impl<'s: 'new, 'new> CloneIn<'new> for Interned<'s> {
+    type Cloned = Interned<'s>;
+
+    fn clone_in(&self, _bump: &'new Bump) -> Interned<'s> {
+        *self
+    }
+}
+
+// Only the first lifetime is the arena's.
+#[derive(CloneIn, Debug, PartialEq)]
+struct Token<'bump, 's> {
+    text: &'bump str,
+    kind: Interned<'s>,
+}
+
+#[derive(CloneIn, Debug, PartialEq)]
+struct NoLifetime {
+    x: i32,
+}
+
+#[derive(CloneIn, Debug, PartialEq)]
+struct Unit;
+
+#[derive(CloneIn)]
+enum Never {}
+
+fn sample<'bump>(b: &'bump Bump) -> Item<'bump> {
+    let callee = b.alloc(Expr::Name(b.alloc_fmt(format_args!("f"))));
+    let call = b.alloc(Expr::Call {
+        callee,
+        args: bumpalo::vec![in b; Expr::Number(1), Expr::Nothing],
+    });
+    Item {
+        label: String::from_str_in("call", b),
+        value: Some(call),
+        span: (3, 9),
+    }
+}
+
+#[test]
+fn derive_rehomes_structs_and_enums() {
+    let new = Bump::new();
+    let copy = {
+        let old = Bump::new();
+        let item = sample(&old);
+        let copy = item.clone_in(&new);
+        assert_eq!(copy, item);
+        copy
+    };
+
+    assert_eq!(copy.label, "call");
+    assert_eq!(copy.span, (3, 9));
+    assert!(new.contains(copy.label.as_ptr()));
+    match copy.value {
+        Some(Expr::Call { callee, args }) => {
+            assert_eq!(**callee, Expr::Name("f"));
+            if let Expr::Name(name) = callee {
+                assert!(new.contains(name.as_ptr()));
+            }
+            assert_eq!(args.len(), 2);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn derive_handles_generics() {
+    let old = Bump::new();
+    let new = Bump::new();
+
+    let pair = Pair(old.alloc(5_u8), Some(2_i64));
+    let copy: Pair<u8, Option<i64>> = pair.clone_in(&new);
+    assert_eq!(copy, pair);
+    assert!(new.contains(copy.0 as *const u8));
+
+    let nested = Pair(old.alloc(NoLifetime { x: 1 }), (Unit, 'c'));
+    assert_eq!(nested.clone_in(&new), nested);
+}
+
+#[test]
+fn derive_keeps_other_lifetimes() {
+    let interned = std::string::String::from("ident");
+    let kind = Interned(&interned);
+
+    let new = Bump::new();
+    let copy = {
+        let old = Bump::new();
+        let token = Token {
+            text: old.alloc_fmt(format_args!("x")),
+            kind,
+        };
+        token.clone_in(&new)
+    };
+    assert_eq!(copy.text, "x");
+    assert!(new.contains(copy.text.as_ptr()));
+    assert!(std::ptr::eq(copy.kind.0, kind.0));
+}
+
+// A derived implementation is usable wherever `CloneIn` is expected.
+fn rehome_all<'new, T: CloneIn<'new>>(items: &[T], bump: &'new Bump) -> &'new [T::Cloned] {
+    CloneIn::clone_in(&items, bump)
+}
+
+#[test]
+fn derive_works_generically() {
+    let old = Bump::new();
+    let new = Bump::new();
+    let items = [sample(&old), sample(&old)];
+    let copies = rehome_all(&items, &new);
+    assert_eq!(copies.len(), 2);
+    assert_eq!(copies[1].label, "call");
+
+    let _ = |never: &Never| never.clone_in(&new);
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "rayon")]
+
+extern crate bumpalo;
+extern crate rayon;
+
+use bumpalo::collections::{String, Vec};
+use bumpalo::Bump;
+use rayon::prelude::*;
+
+#[test]
+fn par_extend_vec_preserves_order() {
+    let b = Bump::new();
+    let mut v = bumpalo::vec![in &b; -1];
+    v.par_extend((0..10_000).into_par_iter().map(|x| x * 3));
+    assert_eq!(v.len(), 10_001);
+    assert_eq!(v[0], -1);
+    for (i, x) in v[1..].iter().enumerate() {
+        assert_eq!(*x, i as i32 * 3);
+    }
+}
+
+#[test]
+fn from_par_iter_in_allocates_exact_capacity() {
+    let b = Bump::new();
+    let v = Vec::from_par_iter_in((0..1000_u64).into_par_iter().filter(|x| x % 2 == 0), &b);
+    assert_eq!(v.len(), 500);
+    assert_eq!(v.capacity(), 500);
+}
+
+#[test]
+fn par_extend_string() {
+    let b = Bump::new();
+    let chars: std::vec::Vec<char> = "parallel".chars().collect();
+
+    let s = String::from_par_iter_in(chars.par_iter(), &b);
+    assert_eq!(s, "parallel");
+
+    let mut s = String::from_par_iter_in(chars.par_iter().cloned(), &b);
+    s.par_extend(vec!["a".to_string(), "b".to_string()]);
+    s.par_extend(vec!["c", "d"]);
+    assert_eq!(s, "parallelabcd");
+}
+
+#[test]
+fn collect_into_bump_indexed_and_unindexed() {
+    use bumpalo::collections::CollectIntoBump;
+
+    let b = Bump::new();
+
+    // Indexed: the length is known, so items are written in place.
+    let v = (0..50_000_u32).into_par_iter().map(|x| x + 1).collect_into_bump(&b);
+    assert_eq!(v.len(), 50_000);
+    assert_eq!(v.capacity(), 50_000);
+    assert!(v.iter().enumerate().all(|(i, &x)| x == i as u32 + 1));
+
+    // Unindexed: the items go through per-thread buffers first.
+    let v = (0..50_000_u32)
+        .into_par_iter()
+        .filter(|x| x % 3 == 0)
+        .collect_into_bump(&b);
+    assert_eq!(v.len(), 16_667);
+    assert_eq!(v.capacity(), 16_667);
+    assert!(v.windows(2).all(|w| w[1] == w[0] + 3));
+}
+
+#[test]
+fn par_extend_indexed_appends_after_existing_items() {
+    let b = Bump::new();
+    let mut v = Vec::from_iter_in(0..3_usize, &b);
+    let extra: std::vec::Vec<usize> = (3..10_003).collect();
+    v.par_extend(extra.par_iter().cloned());
+    assert_eq!(v.len(), 10_003);
+    assert!(v.iter().enumerate().all(|(i, &x)| x == i));
+}
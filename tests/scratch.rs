@@ -0,0 +1,24 @@
+extern crate bumpalo;
+
+use bumpalo::scratch;
+
+#[test]
+fn scratch_arenas_are_reused() {
+    let first = scratch::with(|bump| bump.alloc(1_u64) as *mut u64 as usize);
+    let second = scratch::with(|bump| bump.alloc(2_u64) as *mut u64 as usize);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn nested_scratch_arenas_do_not_clobber_each_other() {
+    scratch::with(|outer| {
+        let x = outer.alloc([1_u64; 16]);
+        scratch::with(|inner| {
+            let y = inner.alloc([2_u64; 16]);
+            assert_eq!(*y, [2; 16]);
+        });
+        let z = outer.alloc([3_u64; 16]);
+        assert_eq!(*x, [1; 16]);
+        assert_eq!(*z, [3; 16]);
+    });
+}
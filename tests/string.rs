@@ -1,5 +1,5 @@
 extern crate bumpalo;
-use bumpalo::{collections::String, Bump};
+use bumpalo::{collections::String, collections::ToStringIn, Bump};
 use std::fmt::Write;
 
 #[test]
@@ -10,3 +10,294 @@ fn format_a_bunch_of_strings() {
         write!(&mut s, " {}", i).unwrap();
     }
 }
+
+#[test]
+fn try_push_str() {
+    let b = Bump::new();
+    let mut s = String::new_in(&b);
+    for _ in 0..1000 {
+        s.try_push_str("hello").unwrap();
+    }
+    assert_eq!(s.len(), 5000);
+    assert!(s.as_str().starts_with("hellohello"));
+}
+
+#[test]
+fn case_conversion_in() {
+    let b = Bump::new();
+
+    let s = String::from_str_in("Hello, Wörld! ΑΒΓ", &b);
+    assert_eq!(s.to_lowercase_in(&b), "hello, wörld! αβγ");
+    assert_eq!(s.to_uppercase_in(&b), "HELLO, WÖRLD! ΑΒΓ");
+
+    // Capital sigma lowercases differently at the end of a word.
+    let s = String::from_str_in("ΣΑΣ Σ", &b);
+    assert_eq!(s.to_lowercase_in(&b), s.as_str().to_lowercase());
+
+    // The result can live in a different arena.
+    let other = Bump::new();
+    let lower = s.to_lowercase_in(&other);
+    drop(s);
+    assert_eq!(lower, "σας σ");
+}
+
+#[test]
+fn replace_and_repeat_in() {
+    let b = Bump::new();
+    let s = String::from_str_in("a-b-c-", &b);
+    assert_eq!(s.replace_in("-", "+=", &b), "a+=b+=c+=");
+    assert_eq!(s.replace_in("", "|", &b), "|a|-|b|-|c|-|");
+    assert_eq!(s.replacen_in("-", "", 2, &b), "abc-");
+    assert_eq!(s.replacen_in("-", "", 0, &b), "a-b-c-");
+    assert_eq!(s.repeat_in(3, &b), "a-b-c-a-b-c-a-b-c-");
+    assert_eq!(s.repeat_in(0, &b), "");
+}
+
+#[test]
+fn utf16_round_trip_in() {
+    let b = Bump::new();
+    let s = String::from_str_in("ab𝄞ц", &b);
+    let utf16 = s.encode_utf16_in(&b);
+    let expected: std::vec::Vec<u16> = s.encode_utf16().collect();
+    assert_eq!(utf16, &expected[..]);
+    assert_eq!(String::from_utf16_lossy_in(&utf16, &b), s);
+
+    let mut broken = utf16.clone();
+    broken.push(0xD800);
+    assert_eq!(String::from_utf16_lossy_in(&broken, &b), "ab𝄞ц\u{FFFD}");
+}
+
+#[test]
+fn from_utf8_lossy_reuses_valid_vec() {
+    let b = Bump::new();
+    let bytes = bumpalo::vec![in &b; b'o', b'k'];
+    let ptr = bytes.as_ptr();
+    let s = String::from_utf8_lossy(bytes);
+    assert_eq!(s, "ok");
+    assert_eq!(s.as_ptr(), ptr);
+
+    let bytes = bumpalo::vec![in &b; b'a', 0xFF, b'b'];
+    assert_eq!(String::from_utf8_lossy(bytes), "a\u{FFFD}b");
+}
+
+#[test]
+fn escape_in() {
+    let b = Bump::new();
+    let s = String::from_str_in("tab\there \"q\" é", &b);
+    assert_eq!(s.escape_debug_in(&b), s.escape_debug().to_string());
+    assert_eq!(s.escape_default_in(&b), s.escape_default().to_string());
+}
+
+#[test]
+fn join_and_concat_in() {
+    use bumpalo::collections::JoinIn;
+
+    let b = Bump::new();
+
+    let empty: [&str; 0] = [];
+    assert_eq!(empty.join_in(", ", &b), "");
+    assert_eq!(empty.concat_in(&b), "");
+    assert_eq!(["one"].join_in(", ", &b), "one");
+
+    let owned = vec![
+        std::string::String::from("α"),
+        std::string::String::from(""),
+        std::string::String::from("β"),
+    ];
+    assert_eq!(owned.join_in("--", &b), "α----β");
+    assert_eq!(owned[..].concat_in(&b), "αβ");
+
+    // Bump strings can be joined too.
+    let parts = [String::from_str_in("x", &b), String::from_str_in("y", &b)];
+    assert_eq!(parts.join_in("+", &b), "x+y");
+}
+
+#[test]
+fn split_in_is_exactly_sized() {
+    use bumpalo::collections::SplitIn;
+
+    let b = Bump::new();
+
+    let fields = "a::b::::c".split_in("::", &b);
+    assert_eq!(fields, ["a", "b", "", "c"]);
+    assert_eq!(fields.capacity(), fields.len());
+
+    let lines = "one\ntwo\r\n\nthree".lines_in(&b);
+    assert_eq!(lines, ["one", "two", "", "three"]);
+    assert_eq!(lines.capacity(), lines.len());
+
+    let words = " \t ".split_whitespace_in(&b);
+    assert!(words.is_empty());
+
+    // The pieces borrow from the input, not from the arena.
+    let text = std::string::String::from("k=v");
+    let kv: &[&str] = {
+        let kv = text.split_in("=", &b);
+        kv.into_bump_slice()
+    };
+    assert_eq!(kv, ["k", "v"]);
+    assert_eq!(kv[0].as_ptr(), text.as_ptr());
+}
+
+#[test]
+fn format_accepts_every_std_form() {
+    let b = Bump::new();
+    let name = "bump";
+    let n = 3;
+
+    assert_eq!(bumpalo::format!(in &b, "no args"), "no args");
+    assert_eq!(bumpalo::format!(in &b, "trailing comma",), "trailing comma");
+    assert_eq!(bumpalo::format!(in &b, "{} {}", 1, 2,), "1 2");
+    assert_eq!(bumpalo::format!(in &b, "{1} {0}", "a", "b"), "b a");
+    assert_eq!(bumpalo::format!(in &b, "{x}-{y}", x = 1, y = "z"), "1-z");
+    assert_eq!(bumpalo::format!(in &b, "{name}:{n:02}"), "bump:03");
+    assert_eq!(bumpalo::format!(in &b, "{:?}", Some("q")), "Some(\"q\")");
+    assert_eq!(bumpalo::format!(in &b, "{{}}"), "{}");
+}
+
+#[test]
+fn write_in_appends_without_trait_import() {
+    let b = Bump::new();
+    let mut s = String::new_in(&b);
+    bumpalo::write_in!(s, "a");
+    bumpalo::write_in!(s, "{}", 'b');
+    bumpalo::write_in!(s, "{c}", c = "c",);
+    assert_eq!(s, "abc");
+
+    // Any `fmt::Write` works, including tail buffers.
+    let mut buf = b.tail_buffer();
+    bumpalo::write_in!(buf, "{}+{}", 1, 1);
+    assert_eq!(buf.as_slice(), b"1+1");
+}
+
+#[test]
+fn format_str_returns_bump_str() {
+    let b = Bump::new();
+    let x = 5;
+    let s: &str = bumpalo::format_str!(in &b, "{x} squared is {}", x * x);
+    assert_eq!(s, "5 squared is 25");
+    assert!(b.contains(s.as_ptr()));
+
+    let t = bumpalo::format_str!(in &b, "plain",);
+    let e = bumpalo::format_str!(in &b, "");
+    assert_eq!(t, "plain");
+    assert_eq!(e, "");
+
+    // Earlier strings are unaffected by later ones.
+    assert_eq!(s, "5 squared is 25");
+}
+
+#[test]
+fn to_string_in() {
+    let b = Bump::new();
+
+    let s = 42.to_string_in(&b);
+    assert_eq!(s, "42");
+    assert_eq!(s.capacity(), 2);
+
+    let s = "already a str".to_string_in(&b);
+    assert_eq!(s, "already a str");
+
+    let mut s = 'c'.to_string_in(&b);
+    s.push_str("har");
+    assert_eq!(s, "char");
+
+    let big = "long".repeat(10_000).to_string_in(&b);
+    assert_eq!(big.len(), 40_000);
+    assert_eq!(big.capacity(), 40_000);
+
+    let s = bumpalo::format!(in &b, "{:05.1}", 3.14159);
+    assert_eq!(s, "003.1");
+    assert_eq!(s.capacity(), s.len());
+}
+
+#[test]
+fn collect_in_string() {
+    use bumpalo::collections::CollectIn;
+
+    let b = Bump::new();
+
+    let s: String = ['a', 'b', 'c'].iter().cloned().collect_in(&b);
+    assert_eq!(s, "abc");
+    assert!(s.capacity() >= 3);
+
+    let s: String = vec!["foo", "bar"].into_iter().collect_in(&b);
+    assert_eq!(s, "foobar");
+
+    // Room for at least a byte per piece is reserved up front.
+    let s: String = vec![""; 4].into_iter().collect_in(&b);
+    assert!(s.is_empty());
+    assert!(s.capacity() >= 4);
+
+    let parts = [String::from_str_in("x", &b), String::from_str_in("yz", &b)];
+    let other = Bump::new();
+    let s: String = parts.iter().cloned().collect_in(&other);
+    assert_eq!(s, "xyz");
+    assert!(other.contains(s.as_ptr()));
+
+    let s: String = vec![String::new_in(&b); 4].into_iter().collect_in(&other);
+    assert!(s.is_empty());
+    assert!(s.capacity() >= 4);
+}
+
+#[test]
+fn trim_in_borrows_unless_it_must_allocate() {
+    use bumpalo::collections::TrimIn;
+
+    let b = Bump::new();
+
+    let text = std::string::String::from("\t one two ");
+    let s = text.squish_in(&b);
+    assert_eq!(s, "one two");
+    assert_eq!(s.as_ptr(), text[2..].as_ptr());
+
+    let s = " one \n\n two\u{3000}three  ".squish_in(&b);
+    assert_eq!(s, "one two three");
+    assert!(b.contains(s.as_ptr()));
+
+    assert_eq!("   ".squish_in(&b), "");
+    assert_eq!("".trim_lines_in(&b), "");
+
+    let text = std::string::String::from("\n a \n\nb \n");
+    let s = text.trim_lines_in(&b);
+    assert_eq!(s, "a\n\nb");
+    assert!(b.contains(s.as_ptr()));
+
+    let text = std::string::String::from("  a\nb\n\nc\n");
+    let s = text.trim_lines_in(&b);
+    assert_eq!(s, "a\nb\n\nc");
+    assert_eq!(s.as_ptr(), text[2..].as_ptr());
+
+    assert_eq!("x\r\ny".trim_lines_in(&b), "x\ny");
+}
+
+#[test]
+fn to_lowercase_in_final_sigma() {
+    let b = Bump::new();
+
+    let odysseus = String::from_str_in("ὈΔΥΣΣΕΎΣ", &b);
+    let lower = odysseus.to_lowercase_in(&b);
+    assert_eq!(lower, "ὀδυσσεύς");
+    assert_eq!(lower.capacity(), lower.len());
+
+    for text in &[
+        "Σ",
+        "ΑΣ",
+        "ΑΣ.",
+        "ΑΣ'Α",
+        "ΑΣ Β",
+        "'ΑΣ'",
+        "Α.Σ",
+        "ΑΣ\u{301}",
+        "ΣΑΣ ΣΑΣ, ΣΣ",
+        "ǅΣ ΣǅΣ",
+        "1Σ 2Σ3",
+        "ΑΣ\u{5b0}Α",
+        "ΑΣ\u{483}Α",
+        "ʰΣ",
+        "ΑʹΣ",
+    ] {
+        let s = String::from_str_in(text, &b);
+        assert_eq!(s.to_lowercase_in(&b), text.to_lowercase(), "lowercasing {:?}", text);
+    }
+}
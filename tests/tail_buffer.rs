@@ -0,0 +1,90 @@
+extern crate bumpalo;
+
+use bumpalo::Bump;
+use std::fmt::Write;
+
+#[test]
+fn tail_buffer_commits_only_written_bytes() {
+    let bump = Bump::new();
+    bump.alloc(0_u8);
+
+    let mut buf = bump.tail_buffer();
+    assert!(buf.capacity() > 0);
+    std::io::Write::write_all(&mut buf, b"abc").unwrap();
+    let start = buf.as_slice().as_ptr() as usize;
+    let bytes = buf.into_bump_slice();
+    assert_eq!(bytes, b"abc");
+
+    // The next allocation comes right after the committed bytes.
+    let next = bump.alloc(1_u8) as *mut u8 as usize;
+    assert_eq!(next, start + 3);
+}
+
+#[test]
+fn tail_buffer_moves_when_full() {
+    let bump = Bump::new();
+    let mut buf = bump.tail_buffer();
+    let first_cap = buf.capacity();
+
+    let mut expected = String::new();
+    for i in 0..100_000_u32 {
+        write!(buf, "{} ", i).unwrap();
+        write!(expected, "{} ", i).unwrap();
+    }
+    assert!(buf.capacity() > first_cap);
+    assert_eq!(buf.as_slice(), expected.as_bytes());
+
+    let s = buf.into_bump_str().unwrap();
+    assert_eq!(*s, expected);
+
+    // Allocations after the buffer don't overlap it.
+    let xs = bump.alloc([0xff_u8; 1024]);
+    assert!(xs.iter().all(|&x| x == 0xff));
+    assert_eq!(*s, expected);
+}
+
+#[test]
+fn dropping_tail_buffer_releases_its_space() {
+    let bump = Bump::new();
+    let a = bump.alloc(0_u8) as *mut u8 as usize;
+    {
+        let mut buf = bump.tail_buffer();
+        buf.extend_from_slice(b"discarded");
+    }
+    let b = bump.alloc(1_u8) as *mut u8 as usize;
+    assert_eq!(b, a + 1);
+}
+
+#[test]
+fn allocating_while_tail_buffer_is_live() {
+    let bump = Bump::new();
+    let mut buf = bump.tail_buffer();
+    buf.extend_from_slice(b"hello");
+
+    // Other allocations don't clobber the buffer's reserved space.
+    let x = bump.alloc([7_u8; 64]);
+    buf.extend_from_slice(b", world");
+    assert_eq!(*x, [7; 64]);
+
+    let s = buf.into_bump_str().unwrap();
+    assert_eq!(s, "hello, world");
+    assert_eq!(*x, [7; 64]);
+}
+
+#[test]
+fn tail_buffer_with_min_align() {
+    let bump = Bump::<16>::with_min_align();
+    let mut buf = bump.tail_buffer();
+    buf.extend_from_slice(b"x");
+    let bytes = buf.into_bump_slice();
+    assert_eq!(bytes, b"x");
+    let next = bump.alloc(1_u8) as *mut u8 as usize;
+    assert_eq!(next % 16, 0);
+    assert_eq!(next, bytes.as_ptr() as usize + 16);
+
+    let mut buf = bump.tail_buffer();
+    buf.write_str(&"y".repeat(10_000)).unwrap();
+    assert_eq!(buf.len(), 10_000);
+    drop(buf);
+    assert_eq!(bump.alloc(2_u8) as *mut u8 as usize % 16, 0);
+}
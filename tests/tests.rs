@@ -7,6 +7,7 @@ use std::slice;
 use std::usize;
 
 #[test]
+#[allow(deprecated)]
 fn can_iterate_over_allocated_things() {
     let mut bump = Bump::new();
 
@@ -84,3 +85,570 @@ fn alloc_overflow() {
     // This should panic.
     bump.alloc_layout(layout);
 }
+
+// A layout that is valid on its own, but that overflows once the chunk footer
+// is added, is reported as an overflow rather than as an allocation failure.
+#[test]
+#[should_panic(expected = "allocation too large, caused overflow")]
+fn alloc_overflow_with_chunk_footer() {
+    let bump = Bump::new();
+    let layout = Layout::from_size_align(isize::MAX as usize - 8, 8).unwrap();
+    bump.alloc_layout(layout);
+}
+
+#[test]
+fn absorb_takes_ownership_of_other_chunks() {
+    let mut bump = Bump::new();
+    bump.alloc(0_u64);
+
+    let other = Bump::new();
+    for i in 0..10_000_u64 {
+        other.alloc(i);
+    }
+
+    let mut other = other;
+    let other_chunks = other.iter_allocated_chunks().count();
+    assert!(other_chunks > 1);
+
+    let x = bump.absorb_with(other, |other| other.alloc(42_u64));
+    assert_eq!(*x, 42);
+
+    let chunks = bump.iter_allocated_chunks().count();
+    assert_eq!(chunks, other_chunks + 1);
+
+    // Allocating after absorbing keeps using our own chunks, and resetting
+    // frees the absorbed chunks.
+    for i in 0..10_000_u64 {
+        bump.alloc(i);
+    }
+    bump.reset();
+    let chunks = bump.iter_allocated_chunks().count();
+    assert_eq!(chunks, 1);
+}
+
+#[test]
+fn bump_is_send() {
+    let bump = std::thread::spawn(|| {
+        let bump = Bump::new();
+        bump.alloc("hello");
+        bump
+    })
+    .join()
+    .unwrap();
+
+    let mut dest = Bump::new();
+    dest.absorb(bump);
+    dest.reset();
+}
+
+#[test]
+fn reset_retire_hands_back_excess_chunks() {
+    let mut bump = Bump::new();
+    for i in 0..10_000_u64 {
+        bump.alloc(i);
+    }
+    let chunks = bump.iter_allocated_chunks().count();
+    assert!(chunks > 1);
+
+    let retired = bump.reset_retire();
+    assert_eq!(retired.len(), chunks - 1);
+
+    let chunks = bump.iter_allocated_chunks().count();
+    assert_eq!(chunks, 1);
+
+    // The retired chunks can be freed on another thread.
+    std::thread::spawn(move || drop(retired)).join().unwrap();
+
+    // Nothing is retired when there is only one chunk.
+    assert!(bump.reset_retire().is_empty());
+}
+
+#[test]
+fn retire_and_drop_deferred() {
+    let bump = Bump::new();
+    for i in 0..10_000_u64 {
+        bump.alloc(i);
+    }
+    let retired = bump.retire();
+    assert!(retired.len() > 1);
+    retired.dealloc_deferred();
+
+    let mut bump = Bump::new();
+    for i in 0..10_000_u64 {
+        bump.alloc(i);
+    }
+    bump.reset_deferred();
+    bump.alloc(1);
+    bump.drop_deferred();
+}
+
+#[test]
+fn new_arena_has_no_chunks_until_first_allocation() {
+    let mut bump = Bump::new();
+    let chunks = bump.iter_allocated_chunks().count();
+    assert_eq!(chunks, 0);
+
+    bump.reset();
+    assert!(bump.reset_retire().is_empty());
+
+    // The first allocation gets a chunk that is big enough, even if it is
+    // larger than the default chunk size.
+    let xs = bump.alloc([7_u8; 4096]);
+    assert!(xs.iter().all(|&x| x == 7));
+    let chunks = bump.iter_allocated_chunks().count();
+    assert_eq!(chunks, 1);
+
+    // Empty arenas can be absorbed, and can absorb.
+    let empty = Bump::new();
+    empty.absorb(bump);
+    empty.absorb(Bump::new());
+    assert_eq!(*empty.alloc(1), 1);
+}
+
+static STATIC_ARENA: std::sync::Mutex<Bump> = std::sync::Mutex::new(Bump::new());
+
+#[test]
+fn arena_in_static() {
+    let mut bump = STATIC_ARENA.lock().unwrap();
+    for i in 0..1000 {
+        assert_eq!(*bump.alloc(i), i);
+    }
+    bump.reset();
+}
+
+#[test]
+fn iter_allocated_chunks() {
+    let mut bump = Bump::new();
+    assert_eq!(bump.iter_allocated_chunks().count(), 0);
+    assert_eq!(bump.iter_allocated_chunks_raw().count(), 0);
+
+    const MAX: u64 = 10_000;
+    for i in 0..MAX {
+        bump.alloc(i);
+    }
+
+    // The raw and safe iterators agree with each other.
+    let raw: Vec<_> = bump.iter_allocated_chunks_raw().collect();
+    let chunks: Vec<_> = bump
+        .iter_allocated_chunks()
+        .map(|chunk| {
+            (
+                chunk.bytes.as_ptr() as *const u8,
+                chunk.bytes.len(),
+                chunk.capacity,
+            )
+        })
+        .collect();
+    assert!(chunks.len() > 1);
+    assert_eq!(raw.len(), chunks.len());
+    for (r, c) in raw.iter().zip(&chunks) {
+        assert_eq!((r.start, r.used, r.capacity), *c);
+        assert!(r.used <= r.capacity);
+    }
+
+    // Every value we allocated is in the chunks, in allocation order.
+    let mut seen = vec![];
+    for chunk in bump.iter_allocated_chunks() {
+        assert_eq!(chunk.bytes.len() % mem::size_of::<u64>(), 0);
+        let values = unsafe {
+            slice::from_raw_parts(
+                chunk.bytes.as_ptr() as *const u64,
+                chunk.bytes.len() / mem::size_of::<u64>(),
+            )
+        };
+        seen.extend_from_slice(values);
+    }
+    assert_eq!(seen, (0..MAX).collect::<Vec<_>>());
+}
+
+fn chunk_lens<const MIN_ALIGN: usize>(bump: &mut Bump<MIN_ALIGN>) -> Vec<usize> {
+    bump.iter_allocated_chunks()
+        .map(|chunk| chunk.bytes.len())
+        .collect()
+}
+
+#[test]
+fn new_chunk_fits_request_and_footer() {
+    let mut bump = Bump::new();
+    bump.alloc(0_u8);
+
+    // Larger than the doubled first chunk, but not large enough to get a
+    // dedicated chunk, so the new chunk is sized just for this request.
+    let size = 40_000;
+    let p = bump.alloc_layout(std::alloc::Layout::from_size_align(size, 8).unwrap());
+    unsafe { p.as_ptr().write_bytes(0xAB, size) };
+    assert_eq!(chunk_lens(&mut bump), [1, size]);
+
+    // That chunk is full, so the next allocation starts another one.
+    bump.alloc(1_u8);
+    assert_eq!(chunk_lens(&mut bump), [1, size, 1]);
+}
+
+#[test]
+fn large_allocations_get_dedicated_chunks() {
+    const LARGE: usize = 1 << 20;
+
+    let mut bump = Bump::new();
+    let a = bump.alloc(1_u64) as *mut u64 as usize;
+    let big = bump.alloc_layout(std::alloc::Layout::from_size_align(LARGE, 16).unwrap());
+    assert_eq!(big.as_ptr() as usize % 16, 0);
+    let b = bump.alloc(2_u64) as *mut u64 as usize;
+
+    // Small allocations keep filling the current chunk.
+    assert_eq!(b, a + 8);
+    assert_eq!(chunk_lens(&mut bump), [LARGE, 16]);
+
+    // Resetting frees the large-object chunk.
+    bump.reset();
+    assert_eq!(chunk_lens(&mut bump), [0]);
+
+    // A large first allocation doesn't become the current chunk either.
+    let mut bump = Bump::new();
+    bump.alloc_layout(std::alloc::Layout::from_size_align(LARGE, 8).unwrap());
+    assert_eq!(chunk_lens(&mut bump), [LARGE]);
+    bump.alloc(3_u8);
+    assert_eq!(chunk_lens(&mut bump), [LARGE, 1]);
+
+    // Absorbing arenas that only have large-object chunks keeps the current
+    // chunk last.
+    let other = Bump::new();
+    other.alloc_layout(std::alloc::Layout::from_size_align(LARGE, 8).unwrap());
+    bump.absorb(other);
+    let mut empty = Bump::new();
+    empty.alloc_layout(std::alloc::Layout::from_size_align(LARGE, 8).unwrap());
+    empty.absorb(bump);
+    assert_eq!(chunk_lens(&mut empty), [LARGE, LARGE, LARGE, 1]);
+    empty.alloc(4_u8);
+    assert_eq!(chunk_lens(&mut empty), [LARGE, LARGE, LARGE, 2]);
+    empty.reset();
+    assert_eq!(chunk_lens(&mut empty), [0]);
+}
+
+#[test]
+fn min_align_keeps_the_bump_finger_aligned() {
+    let mut bump = Bump::<16>::with_min_align();
+    for i in 0..1000_u32 {
+        let a = bump.alloc(i as u8) as *mut u8 as usize;
+        let b = bump.alloc(i) as *mut u32 as usize;
+        let c = bump.alloc([i as u64; 3]) as *mut [u64; 3] as usize;
+        assert_eq!(a % 16, 0);
+        assert_eq!(b % 16, 0);
+        assert_eq!(c % 16, 0);
+    }
+
+    // Over-aligned requests still get their alignment.
+    let big_align = std::alloc::Layout::from_size_align(8, 64).unwrap();
+    assert_eq!(bump.alloc_layout(big_align).as_ptr() as usize % 64, 0);
+    assert_eq!(bump.alloc(1_u8) as *mut u8 as usize % 16, 0);
+
+    // Sizes are rounded up, and so is the first chunk's alignment.
+    bump.reset();
+    let mut bump = Bump::<128>::with_min_align();
+    let a = bump.alloc(1_u8) as *mut u8 as usize;
+    let b = bump.alloc(2_u8) as *mut u8 as usize;
+    assert_eq!(a % 128, 0);
+    assert_eq!(b, a + 128);
+    assert_eq!(chunk_lens(&mut bump), [256]);
+}
+
+#[test]
+fn uninit_and_zeroed_allocations() {
+    use std::mem::MaybeUninit;
+
+    let bump = Bump::new();
+
+    let x = bump.alloc_uninit::<u64>();
+    assert_eq!(x as *mut MaybeUninit<u64> as usize % 8, 0);
+    assert_eq!(*x.write(7), 7);
+
+    let xs = bump.alloc_uninit_slice::<u32>(1000);
+    assert_eq!(xs.len(), 1000);
+    assert_eq!(xs.as_ptr() as usize % 4, 0);
+    for (i, x) in xs.iter_mut().enumerate() {
+        x.write(i as u32);
+    }
+
+    assert!(bump.alloc_uninit_slice::<u32>(0).is_empty());
+    assert_eq!(bump.alloc_uninit_slice::<()>(usize::MAX).len(), usize::MAX);
+
+    // Zeroed allocations are cleared even when the arena's memory isn't.
+    let mut bump = Bump::new();
+    bump.alloc([0xff_u8; 256]);
+    bump.reset();
+    let z = unsafe { bump.alloc_zeroed::<[u8; 256]>() };
+    assert!(z.iter().all(|&b| b == 0));
+    let zs = unsafe { bump.alloc_slice_zeroed::<u16>(300) };
+    assert_eq!(zs.len(), 300);
+    assert!(zs.iter().all(|&b| b == 0));
+}
+
+#[test]
+fn alloc_try_with_rolls_back_on_error() {
+    let bump = Bump::new();
+
+    let a = bump.alloc(1_u64) as *mut u64 as usize;
+    let err: Result<&mut [u64; 4], &str> = bump.alloc_try_with(|| Err("nope"));
+    assert_eq!(err.unwrap_err(), "nope");
+    // The failed allocation's space is reused.
+    let b = bump.alloc([2_u64; 4]) as *mut [u64; 4] as usize;
+    assert_eq!(b, a + 8);
+
+    let ok: Result<_, ()> = bump.alloc_try_with(|| Ok([3_u64; 4]));
+    assert_eq!(*ok.unwrap(), [3; 4]);
+
+    // Nothing is rolled back if something else was allocated in between.
+    let mut inner = 0;
+    let err: Result<&mut u64, ()> = bump.alloc_try_with(|| {
+        inner = bump.alloc(4_u64) as *mut u64 as usize;
+        Err(())
+    });
+    assert!(err.is_err());
+    assert_eq!(unsafe { *(inner as *const u64) }, 4);
+    let c = bump.alloc(5_u64) as *mut u64 as usize;
+    assert!(c > inner);
+}
+
+#[test]
+fn alloc_slice_try_fill_iter_drops_and_rolls_back_on_error() {
+    use std::rc::Rc;
+
+    let bump = Bump::new();
+    let rc = Rc::new(());
+
+    let a = bump.alloc(1_u64) as *mut u64 as usize;
+    let items = vec![Ok(rc.clone()), Ok(rc.clone()), Err(()), Ok(rc.clone())];
+    let err = bump.alloc_slice_try_fill_iter(items);
+    assert!(err.is_err());
+    // The values written before the error were dropped.
+    assert_eq!(Rc::strong_count(&rc), 1);
+    let b = bump.alloc(2_u64) as *mut u64 as usize;
+    assert_eq!(b, a + 8);
+
+    let xs = bump.alloc_slice_try_fill_iter((0..100).map(Ok::<_, ()>));
+    assert_eq!(xs.unwrap().len(), 100);
+}
+
+#[test]
+fn header_slice_is_one_contiguous_allocation() {
+    use bumpalo::HeaderSlice;
+    use std::mem;
+
+    let bump = Bump::new();
+
+    let hs: &mut HeaderSlice<u8, u64> = bump.alloc_header_slice_copy(7, &[1, 2, 3]);
+    let start = hs as *mut HeaderSlice<u8, u64> as *mut u8 as usize;
+    assert_eq!(hs.header, 7);
+    assert_eq!(hs.slice, [1, 2, 3]);
+    assert_eq!(hs.slice.as_ptr() as usize, start + 8);
+    assert_eq!(mem::size_of_val(hs), 32);
+    assert_eq!(mem::align_of_val(hs), 8);
+
+    // The header and slice can be mutated independently.
+    let HeaderSlice { header, slice } = hs;
+    *header += 1;
+    slice[0] = *header as u64;
+    assert_eq!(hs.slice, [8, 2, 3]);
+
+    // Trailing padding is included, and empty slices work.
+    let hs = bump.alloc_header_slice_fill_iter(1_u64, (0..3).map(|i| i as u8));
+    assert_eq!(mem::size_of_val(hs), 16);
+    assert_eq!(hs.slice, [0, 1, 2]);
+    let hs = bump.alloc_header_slice_fill_iter((), std::iter::empty::<String>());
+    assert!(hs.slice.is_empty());
+    assert_eq!(mem::size_of_val(hs), 0);
+}
+
+#[test]
+fn alloc_many_allocates_once() {
+    let bump = Bump::new();
+
+    let (a, b, c) = bump.alloc_many((1_u8, 2_u64, [3_u16; 3]));
+    let a = a as *mut u8 as usize;
+    let b = b as *mut u64 as usize;
+    let c = c as *mut [u16; 3] as usize;
+    assert_eq!(b % 8, 0);
+    assert_eq!(c % 2, 0);
+
+    // All three live in one 16-byte region.
+    let lo = a.min(b).min(c);
+    let hi = (a + 1).max(b + 8).max(c + 6);
+    assert!(hi - lo <= 16);
+
+    let (x,) = bump.alloc_many((String::from("x"),));
+    x.push('y');
+    assert_eq!(x, "xy");
+    unsafe { std::ptr::drop_in_place(x) };
+}
+
+#[test]
+fn poll_pinned_future() {
+    use std::future::Future;
+    use std::marker::PhantomPinned;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+    static WAKES: AtomicUsize = AtomicUsize::new(0);
+
+    fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+    fn wake(_: *const ()) {
+        WAKES.fetch_add(1, Ordering::SeqCst);
+    }
+    fn drop(_: *const ()) {}
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, wake, wake, drop);
+
+    // A self-referential future: it remembers the address of its own counter
+    // on the first poll, and relies on being pinned for it to stay valid.
+    struct Countdown {
+        n: u32,
+        counter: *mut u32,
+        _pinned: PhantomPinned,
+    }
+
+    impl Future for Countdown {
+        type Output = &'static str;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<&'static str> {
+            let this = unsafe { self.get_unchecked_mut() };
+            if this.counter.is_null() {
+                this.counter = &mut this.n;
+            }
+            assert_eq!(this.counter, &mut this.n as *mut u32);
+
+            unsafe {
+                if *this.counter == 0 {
+                    return Poll::Ready("liftoff");
+                }
+                *this.counter -= 1;
+            }
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    let bump = Bump::new();
+    let mut fut = bump.alloc_pinned_with(|| Countdown {
+        n: 3,
+        counter: std::ptr::null_mut(),
+        _pinned: PhantomPinned,
+    });
+
+    // Other allocations don't disturb the pinned future.
+    for i in 0..1000 {
+        bump.alloc(i);
+    }
+
+    let waker = unsafe { Waker::from_raw(clone(std::ptr::null())) };
+    let mut cx = Context::from_waker(&waker);
+    for _ in 0..3 {
+        assert_eq!(fut.as_mut().poll(&mut cx), Poll::Pending);
+    }
+    assert_eq!(WAKES.load(Ordering::SeqCst), 3);
+    assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready("liftoff"));
+}
+
+#[test]
+fn contains_and_chunk_index() {
+    let bump = Bump::new();
+    let other = Bump::new();
+
+    let mut ptrs = vec![];
+    for i in 0..10_000_u64 {
+        ptrs.push(bump.alloc(i) as *const u64 as *const u8);
+    }
+    let big = bump.alloc_layout(std::alloc::Layout::from_size_align(1 << 20, 8).unwrap());
+    ptrs.push(big.as_ptr());
+    ptrs.push(unsafe { big.as_ptr().add((1 << 20) - 1) });
+    let elsewhere = other.alloc(0_u64) as *const u64 as *const u8;
+    let boxed = Box::new(0_u64);
+
+    let mut index = bump.chunk_index();
+    assert!(!index.is_stale());
+    for &p in &ptrs {
+        assert!(bump.contains(p));
+        assert!(index.contains(p));
+    }
+    for &p in &[elsewhere, &*boxed as *const u64 as *const u8] {
+        assert!(!bump.contains(p));
+        assert!(!index.contains(p));
+    }
+
+    // The unallocated space at the end of the current chunk isn't contained.
+    let last = bump.alloc(0_u8) as *const u8;
+    let past = unsafe { last.add(1) };
+    assert!(!bump.contains(past));
+    assert!(!index.contains(past));
+
+    // Chunks added after the index was built are still found.
+    let mut more = vec![];
+    for i in 0..10_000_u64 {
+        more.push(bump.alloc(i) as *const u64 as *const u8);
+    }
+    assert!(index.is_stale());
+    assert!(more.iter().all(|&p| index.contains(p)));
+    index.refresh();
+    assert!(!index.is_stale());
+    assert!(more.iter().all(|&p| index.contains(p)));
+    assert!(!index.contains(elsewhere));
+}
+
+#[test]
+fn alloc_fmt_is_exactly_sized() {
+    use std::fmt;
+
+    let bump = Bump::new();
+
+    // Fits in the current chunk.
+    let s = bump.alloc_fmt(format_args!("{}-{}", "a", 1));
+    assert_eq!(s, "a-1");
+    let next = bump.alloc(0_u8) as *const u8;
+    assert_eq!(next, unsafe { s.as_ptr().add(3) });
+
+    // Much larger than the current chunk, so it needs the measuring pass.
+    let long = "x".repeat(100_000);
+    let s = bump.alloc_fmt(format_args!("<{}>", long));
+    assert_eq!(s.len(), 100_002);
+    assert!(s.starts_with("<x") && s.ends_with("x>"));
+    assert!(bump.contains(s.as_ptr()));
+
+    // A `Display` that writes less on its second pass is truncated safely.
+    struct Shrinking(std::cell::Cell<usize>);
+    impl fmt::Display for Shrinking {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let n = self.0.get();
+            self.0.set(n / 2);
+            for _ in 0..n {
+                f.write_str("y")?;
+            }
+            Ok(())
+        }
+    }
+    let shrinking = Shrinking(std::cell::Cell::new(200_000));
+    let s = bump.alloc_fmt(format_args!("{}", shrinking));
+    assert_eq!(s.len(), 100_000);
+    assert!(s.bytes().all(|b| b == b'y'));
+}
+
+#[test]
+#[should_panic(expected = "second pass")]
+fn alloc_fmt_panics_if_second_pass_is_longer() {
+    use std::cell::Cell;
+    use std::fmt;
+
+    struct Growing(Cell<usize>);
+    impl fmt::Display for Growing {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let n = self.0.get();
+            self.0.set(n * 2);
+            for _ in 0..n {
+                f.write_str("z")?;
+            }
+            Ok(())
+        }
+    }
+
+    let bump = Bump::new();
+    bump.alloc_fmt(format_args!("{}", Growing(Cell::new(100_000))));
+}
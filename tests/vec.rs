@@ -1,5 +1,8 @@
 extern crate bumpalo;
-use bumpalo::{Bump, collections::Vec};
+use bumpalo::{
+    collections::{CollectionAllocErr, Vec},
+    Bump,
+};
 
 #[test]
 fn push_a_bunch_of_items() {
@@ -9,3 +12,532 @@ fn push_a_bunch_of_items() {
         v.push(x);
     }
 }
+
+#[test]
+fn try_push_a_bunch_of_items() {
+    let b = Bump::new();
+    let mut v = Vec::new_in(&b);
+    for x in 0..10_000 {
+        v.try_push(x).unwrap();
+    }
+    assert_eq!(v.len(), 10_000);
+    assert!(v.iter().cloned().eq(0..10_000));
+}
+
+#[test]
+fn try_reserve_reports_capacity_overflow() {
+    let b = Bump::new();
+    let mut v = bumpalo::vec![in &b; 1_u32, 2, 3];
+    assert_eq!(
+        v.try_reserve(usize::MAX),
+        Err(CollectionAllocErr::CapacityOverflow)
+    );
+    assert_eq!(
+        v.try_reserve_exact(usize::MAX),
+        Err(CollectionAllocErr::CapacityOverflow)
+    );
+    assert_eq!(
+        Vec::<u32>::try_with_capacity_in(usize::MAX, &b).unwrap_err(),
+        CollectionAllocErr::CapacityOverflow
+    );
+    assert_eq!(v, [1, 2, 3]);
+}
+
+// Requests that fit in a `Layout` but that no global allocator can satisfy
+// are reported as allocation errors, and leave the arena usable.
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn try_reserve_reports_alloc_failure() {
+    let huge = isize::MAX as usize / 2;
+
+    let b = Bump::new();
+    let mut v = bumpalo::vec![in &b; 1_u8, 2, 3];
+    assert_eq!(v.try_reserve(huge), Err(CollectionAllocErr::AllocErr));
+    assert_eq!(v.try_reserve_exact(huge), Err(CollectionAllocErr::AllocErr));
+    assert_eq!(
+        Vec::<u8>::try_with_capacity_in(huge, &b).unwrap_err(),
+        CollectionAllocErr::AllocErr
+    );
+    assert_eq!(v, [1, 2, 3]);
+
+    v.try_extend_from_slice(&[4, 5, 6]).unwrap();
+    assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+}
+
+// Requests that only overflow once the chunk footer is added are reported as
+// allocation errors too, rather than panicking.
+#[test]
+fn try_reserve_too_large_for_a_chunk() {
+    let b = Bump::new();
+    let mut v: Vec<u8> = Vec::new_in(&b);
+    assert_eq!(
+        v.try_reserve_exact(isize::MAX as usize - 8),
+        Err(CollectionAllocErr::AllocErr)
+    );
+    v.push(1);
+    assert_eq!(v, [1]);
+}
+
+#[test]
+fn retain_mut() {
+    let b = Bump::new();
+    let mut vec = bumpalo::vec![in &b; 9, 9, 9, 9];
+    let mut i = 0;
+    vec.retain_mut(|x| {
+        i += 1;
+        *x += i;
+        i != 4
+    });
+    assert_eq!(vec, [10, 11, 12]);
+}
+
+#[test]
+fn extend_from_within() {
+    let b = Bump::new();
+
+    let mut v = bumpalo::vec![in &b; String::from("a"), String::from("b"), String::from("c")];
+    v.extend_from_within(1..=2);
+    v.extend_from_within(..=1);
+    assert_eq!(v, ["a", "b", "c", "b", "c", "a", "b"]);
+
+    let mut v = bumpalo::vec![in &b; 1, 2, 3];
+    v.extend_from_within(3..);
+    v.extend_from_within(..0);
+    assert_eq!(v, [1, 2, 3]);
+
+    let mut v: Vec<()> = bumpalo::vec![in &b; (), (), ()];
+    v.extend_from_within(..);
+    assert_eq!(v.len(), 6);
+}
+
+#[test]
+#[should_panic]
+fn extend_from_within_out_of_range() {
+    let b = Bump::new();
+    let mut v = bumpalo::vec![in &b; 1, 2];
+    v.extend_from_within(..3);
+}
+
+#[test]
+fn spare_capacity() {
+    let b = Bump::new();
+    let mut v = Vec::with_capacity_in(8, &b);
+    v.push(1_u32);
+
+    let (init, spare) = v.split_at_spare_mut();
+    assert_eq!(init, [1]);
+    assert_eq!(spare.len(), 7);
+    for (i, slot) in spare.iter_mut().enumerate() {
+        slot.write(i as u32 + 2);
+    }
+    unsafe {
+        v.set_len(8);
+    }
+    assert_eq!(v, [1, 2, 3, 4, 5, 6, 7, 8]);
+    assert!(v.spare_capacity_mut().is_empty());
+}
+
+#[test]
+fn push_within_capacity() {
+    let b = Bump::new();
+    let mut v = Vec::with_capacity_in(1, &b);
+    assert_eq!(v.push_within_capacity(1), Ok(()));
+    assert_eq!(v.push_within_capacity(2), Err(2));
+    v.reserve(1);
+    assert_eq!(v.push_within_capacity(2), Ok(()));
+    assert_eq!(v, [1, 2]);
+}
+
+#[test]
+fn into_bump_slice_mut_and_raw_parts() {
+    let b = Bump::new();
+    let v = bumpalo::vec![in &b; 1, 2, 3];
+    let s = v.into_bump_slice_mut();
+    s[0] = 4;
+    assert_eq!(s, [4, 2, 3]);
+
+    let mut v = Vec::with_capacity_in(4, &b);
+    v.extend_from_slice(&[1, 2]);
+    let (ptr, len, cap) = v.into_raw_parts();
+    assert_eq!((len, cap), (2, 4));
+    let v = unsafe { Vec::from_raw_parts_in(ptr, len, cap, &b) };
+    assert_eq!(v, [1, 2]);
+}
+
+#[test]
+fn drain_filter_drop_filters_the_rest() {
+    let b = Bump::new();
+    let mut v = bumpalo::vec![in &b; 1, 2, 3, 4, 5, 6];
+    assert_eq!(v.drain_filter(|x| *x % 2 == 0).next(), Some(2));
+    assert_eq!(v, [1, 3, 5]);
+}
+
+#[test]
+fn extract_if_empty() {
+    let b = Bump::new();
+    let mut v: Vec<i32> = Vec::new_in(&b);
+    {
+        let mut iter = v.extract_if(.., |_| true);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+    assert_eq!(v.len(), 0);
+    assert_eq!(v, []);
+}
+
+#[test]
+fn extract_if_zst() {
+    let b = Bump::new();
+    let mut v = bumpalo::vec![in &b; (), (), (), (), ()];
+    let initial_len = v.len();
+    let mut count = 0;
+    {
+        let mut iter = v.extract_if(.., |_| true);
+        assert_eq!(iter.size_hint(), (0, Some(initial_len)));
+        while let Some(_) = iter.next() {
+            count += 1;
+            assert_eq!(iter.size_hint(), (0, Some(initial_len - count)));
+        }
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+    assert_eq!(count, initial_len);
+    assert_eq!(v.len(), 0);
+}
+
+#[test]
+fn extract_if_false() {
+    let b = Bump::new();
+    let mut v = bumpalo::vec![in &b; 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    let initial_len = v.len();
+    let mut count = 0;
+    {
+        let mut iter = v.extract_if(.., |_| false);
+        assert_eq!(iter.size_hint(), (0, Some(initial_len)));
+        for _ in iter.by_ref() {
+            count += 1;
+        }
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+    assert_eq!(count, 0);
+    assert_eq!(v.len(), initial_len);
+    assert_eq!(v, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+}
+
+#[test]
+fn extract_if_true() {
+    let b = Bump::new();
+    let mut v = bumpalo::vec![in &b; 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    let initial_len = v.len();
+    let mut count = 0;
+    {
+        let mut iter = v.extract_if(.., |_| true);
+        assert_eq!(iter.size_hint(), (0, Some(initial_len)));
+        while let Some(_) = iter.next() {
+            count += 1;
+            assert_eq!(iter.size_hint(), (0, Some(initial_len - count)));
+        }
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+    assert_eq!(count, initial_len);
+    assert_eq!(v.len(), 0);
+    assert_eq!(v, []);
+}
+
+#[test]
+fn extract_if_ranges() {
+    let b = Bump::new();
+    let mut v = bumpalo::vec![in &b; 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    let mut count = 0;
+    let it = v.extract_if(1..=3, |_| {
+        count += 1;
+        true
+    });
+    assert_eq!(it.count(), 3);
+    assert_eq!(v, [1, 5, 6, 7, 8, 9, 10]);
+    assert_eq!(count, 3);
+
+    let it = v.extract_if(1..=3, |_| false);
+    assert_eq!(it.count(), 0);
+    assert_eq!(v, [1, 5, 6, 7, 8, 9, 10]);
+}
+
+#[test]
+#[should_panic]
+fn extract_if_out_of_bounds() {
+    let b = Bump::new();
+    let mut v = bumpalo::vec![in &b; 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    let _ = v.extract_if(5..11, |_| true).for_each(drop);
+}
+
+#[test]
+fn extract_if_complex() {
+    let b = Bump::new();
+
+    {
+        //                [+xxx++++++xxxxx++++x+x++]
+        let mut vec = bumpalo::vec![in &b;
+            1, 2, 4, 6, 7, 9, 11, 13, 15, 17, 18, 20, 22, 24, 26, 27, 29, 31, 33, 34, 35, 36, 37,
+            39
+        ];
+
+        let removed = Vec::from_iter_in(vec.extract_if(.., |x| *x % 2 == 0), &b);
+        assert_eq!(removed.len(), 10);
+        assert_eq!(removed, [2, 4, 6, 18, 20, 22, 24, 26, 34, 36]);
+
+        assert_eq!(vec.len(), 14);
+        assert_eq!(vec, [1, 7, 9, 11, 13, 15, 17, 27, 29, 31, 33, 35, 37, 39]);
+    }
+
+    {
+        //                [xxx++++++xxxxx++++x+x++]
+        let mut vec = bumpalo::vec![in &b;
+            2, 4, 6, 7, 9, 11, 13, 15, 17, 18, 20, 22, 24, 26, 27, 29, 31, 33, 34, 35, 36, 37, 39
+        ];
+
+        let removed = Vec::from_iter_in(vec.extract_if(.., |x| *x % 2 == 0), &b);
+        assert_eq!(removed.len(), 10);
+        assert_eq!(removed, [2, 4, 6, 18, 20, 22, 24, 26, 34, 36]);
+
+        assert_eq!(vec.len(), 13);
+        assert_eq!(vec, [7, 9, 11, 13, 15, 17, 27, 29, 31, 33, 35, 37, 39]);
+    }
+
+    {
+        //                [xxx++++++xxxxx++++x+x]
+        let mut vec = bumpalo::vec![in &b;
+            2, 4, 6, 7, 9, 11, 13, 15, 17, 18, 20, 22, 24, 26, 27, 29, 31, 33, 34, 35, 36
+        ];
+
+        let removed = Vec::from_iter_in(vec.extract_if(.., |x| *x % 2 == 0), &b);
+        assert_eq!(removed.len(), 10);
+        assert_eq!(removed, [2, 4, 6, 18, 20, 22, 24, 26, 34, 36]);
+
+        assert_eq!(vec.len(), 11);
+        assert_eq!(vec, [7, 9, 11, 13, 15, 17, 27, 29, 31, 33, 35]);
+    }
+
+    {
+        //                [xxxxxxxxxx+++++++++++]
+        let mut vec = bumpalo::vec![in &b;
+            2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 1, 3, 5, 7, 9, 11, 13, 15, 17, 19
+        ];
+
+        let removed = Vec::from_iter_in(vec.extract_if(.., |x| *x % 2 == 0), &b);
+        assert_eq!(removed.len(), 10);
+        assert_eq!(removed, [2, 4, 6, 8, 10, 12, 14, 16, 18, 20]);
+
+        assert_eq!(vec.len(), 10);
+        assert_eq!(vec, [1, 3, 5, 7, 9, 11, 13, 15, 17, 19]);
+    }
+
+    {
+        //                [+++++++++++xxxxxxxxxx]
+        let mut vec = bumpalo::vec![in &b;
+            1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20
+        ];
+
+        let removed = Vec::from_iter_in(vec.extract_if(.., |x| *x % 2 == 0), &b);
+        assert_eq!(removed.len(), 10);
+        assert_eq!(removed, [2, 4, 6, 8, 10, 12, 14, 16, 18, 20]);
+
+        assert_eq!(vec.len(), 10);
+        assert_eq!(vec, [1, 3, 5, 7, 9, 11, 13, 15, 17, 19]);
+    }
+}
+
+#[test]
+fn extract_if_unconsumed() {
+    let b = Bump::new();
+    let mut vec = bumpalo::vec![in &b; 1, 2, 3, 4];
+    let drain = vec.extract_if(.., |&mut x| x % 2 != 0);
+    drop(drain);
+    assert_eq!(vec, [1, 2, 3, 4]);
+}
+
+#[test]
+fn extract_if_consumed_panic() {
+    use std::cell::Cell;
+    use std::panic::{self, AssertUnwindSafe};
+
+    struct Check<'a> {
+        index: usize,
+        drop_counts: &'a [Cell<usize>],
+    }
+
+    impl Drop for Check<'_> {
+        fn drop(&mut self) {
+            let count = &self.drop_counts[self.index];
+            count.set(count.get() + 1);
+        }
+    }
+
+    let check_count = 10;
+    let drop_counts: std::vec::Vec<_> = (0..check_count).map(|_| Cell::new(0)).collect();
+    let b = Bump::new();
+    let mut data = Vec::from_iter_in(
+        (0..check_count).map(|index| Check {
+            index,
+            drop_counts: &drop_counts,
+        }),
+        &b,
+    );
+
+    let _ = panic::catch_unwind(AssertUnwindSafe(move || {
+        let filter = |c: &mut Check| {
+            if c.index == 2 {
+                panic!("panic at index: {}", c.index);
+            }
+            // Always removed the first 2 elements.
+            c.index < 2
+        };
+        let drain = data.extract_if(.., filter);
+
+        // Consume the iterator, dropping the extracted elements.
+        drain.for_each(drop);
+    }));
+
+    // The extracted elements were dropped exactly once. Bump-allocated
+    // vectors don't drop their elements, so the rest were never dropped.
+    for (index, count) in drop_counts.iter().enumerate() {
+        let expected = if index < 2 { 1 } else { 0 };
+        assert_eq!(
+            expected,
+            count.get(),
+            "unexpected drop count at index: {}",
+            index
+        );
+    }
+}
+
+#[test]
+fn into_iter_into_bump_slice() {
+    let b = Bump::new();
+    let v = bumpalo::vec![in &b; 1, 2, 3, 4];
+    let mut it = v.into_iter();
+    assert_eq!(it.next(), Some(1));
+    assert_eq!(it.next_back(), Some(4));
+    assert_eq!(it.into_bump_slice(), [2, 3]);
+
+    let v = bumpalo::vec![in &b; (), (), ()];
+    let mut it = v.into_iter();
+    it.next();
+    assert_eq!(it.into_bump_slice_mut().len(), 2);
+}
+
+#[test]
+fn vec_macro_forms() {
+    let b = Bump::new();
+
+    let v: Vec<u8> = bumpalo::vec![in &b];
+    assert!(v.is_empty());
+    let v: Vec<u8> = bumpalo::vec![in &b,];
+    assert!(v.is_empty());
+    let v: Vec<u8> = bumpalo::vec![in &b;];
+    assert!(v.is_empty());
+
+    let v = bumpalo::vec![in &b; 1, 2, 3,];
+    assert_eq!(v, [1, 2, 3]);
+    assert_eq!(v.capacity(), 3);
+
+    let v = bumpalo::vec![in &b; "x"; 4,];
+    assert_eq!(v, ["x"; 4]);
+    assert_eq!(v.capacity(), 4);
+
+    let v = bumpalo::vec![in &b; std::string::String::from("s"); 0];
+    assert!(v.is_empty());
+
+    let owned = bumpalo::vec![in &b; std::string::String::from("a"), "b".to_string()];
+    assert_eq!(owned, ["a", "b"]);
+}
+
+#[test]
+fn from_slice_in() {
+    let b = Bump::new();
+    let src = [std::string::String::from("one"), "two".to_string()];
+    let v = Vec::from_slice_in(&src, &b);
+    assert_eq!(v, src);
+    assert_eq!(v.capacity(), 2);
+
+    let empty: Vec<u64> = Vec::from_slice_in(&[], &b);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn vec_macro_repeat() {
+    use std::rc::Rc;
+
+    let b = Bump::new();
+
+    let v = bumpalo::vec![in &b; 0u64; 1000];
+    assert_eq!(v.len(), 1000);
+    assert_eq!(v.capacity(), 1000);
+    assert!(v.iter().all(|&x| x == 0));
+
+    // The element's type can be left to inference.
+    let v: Vec<Option<std::string::String>> = bumpalo::vec![in &b; None; 3];
+    assert_eq!(v, [None, None, None]);
+
+    // Elements are cloned for all but the last slot, which gets the original.
+    let rc = Rc::new(5);
+    let v = bumpalo::vec![in &b; rc.clone(); 3];
+    assert_eq!(v.len(), 3);
+    assert_eq!(Rc::strong_count(&rc), 4);
+
+    let v = bumpalo::vec![in &b; rc.clone(); 0];
+    assert!(v.is_empty());
+    assert_eq!(Rc::strong_count(&rc), 4);
+}
+
+#[test]
+fn vec_from_slice_macro() {
+    let b = Bump::new();
+
+    let numbers = [1u32, 2, 3];
+    let v = bumpalo::vec_from_slice![in &b; numbers];
+    assert_eq!(v, numbers);
+    assert_eq!(v.capacity(), 3);
+
+    let v = bumpalo::vec_from_slice![in &b; &numbers[1..],];
+    assert_eq!(v, [2, 3]);
+
+    let words = vec![std::string::String::from("a"), "b".to_string()];
+    let v = bumpalo::vec_from_slice![in &b; words];
+    assert_eq!(v, &words[..]);
+    assert_eq!(v.capacity(), 2);
+
+    let v: Vec<std::string::String> = bumpalo::vec_from_slice![in &b; []];
+    assert!(v.is_empty());
+
+    let v = Vec::from_slice_copy_in(&numbers, &b);
+    assert_eq!(v, numbers);
+    assert_eq!(v.capacity(), 3);
+}
+
+#[test]
+fn collect_in() {
+    use bumpalo::collections::{CollectIn, FromIteratorIn};
+
+    let b = Bump::new();
+
+    let v = (0..5).collect_in::<Vec<_>>(&b);
+    assert_eq!(v, [0, 1, 2, 3, 4]);
+    assert_eq!(v.capacity(), 5);
+
+    let v: Vec<&str> = "a b c".split(' ').collect_in(&b);
+    assert_eq!(v, ["a", "b", "c"]);
+
+    fn build<'bump, C: FromIteratorIn<'bump, u8>>(b: &'bump Bump) -> C {
+        (1..=3).collect_in(b)
+    }
+    let v: Vec<u8> = build(&b);
+    assert_eq!(v, [1, 2, 3]);
+}